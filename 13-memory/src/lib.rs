@@ -246,6 +246,54 @@ html_playground_url = "https://play.rust-lang.org/",
 //! foobar.set_foo("The answer");
 //! # println!("Result: {:?}", foobar);
 //! ```
+//!
+//! # Propositional logic
+//!
+//! Exercises of this step build a small logic engine around a `Formula` enum, such as `a & !b`.
+//! It's an _abstract syntax tree_: operators are nodes, while constants and variables are leaves.
+//! Like `List` above, it's a recursive type, so operands have to be boxed. Without indirection, size
+//! of `Formula` would be infinite and compiler rejects it:
+//!
+//! ```rust
+//! enum Formula {
+//!     Var(String),
+//!     Not(Box<Formula>),
+//!     And(Box<Formula>, Box<Formula>),
+//! }
+//!
+//! fn count_variables(formula: &Formula) -> usize {
+//!     match *formula {
+//!         Formula::Var(_) => 1,
+//!         Formula::Not(ref inner) => count_variables(inner),
+//!         Formula::And(ref left, ref right) => count_variables(left) + count_variables(right),
+//!     }
+//! }
+//!
+//! let a = Formula::Var(String::from("a"));
+//! let b = Formula::Var(String::from("b"));
+//! let formula = Formula::And(Box::new(a), Box::new(Formula::Not(Box::new(b))));
+//! assert_eq!(2, count_variables(&formula));
+//! ```
+//!
+//! Formulas are also written as text. Printing (`Display`) and parsing (`parse`) are two sides of
+//! the same contract: `parse(&formula.to_string())` must give same formula back, and tests check it
+//! for every formula. Operators from lowest to highest precedence are `<->`, `->`, `|`, `&` and `!`.
+//!
+//! * Printing only writes parentheses an operand needs: when its operator binds less tightly
+//!   (`(a | b) & c`), or when it's on the side operator doesn't group from. `&` groups from left,
+//!   so `a & b & c` is `(a & b) & c`, whereas `->` groups from right: `a -> b -> c` is `a -> (b -> c)`.
+//!
+//! * Parsing is a _recursive descent_: one function per precedence level, from lowest to highest,
+//!   each one calling next level to read its operands. A parenthesized formula starts over from
+//!   lowest level.
+//!
+//! * Variable names are made of letters, digits and `_`, but can't be `true` or `false`, so that
+//!   printed variables are always parsed back as variables.
+//!
+//! Finally, a formula with `n` variables has `2^n` assignments of values, which make its truth table.
+//! Two formulas are equivalent when they have same value for every assignment, and a formula is a
+//! tautology when it's `true` for every assignment. Assignments are enumerated one at a time, so
+//! that checking stops at first counterexample.
 
 
 #[cfg(test)]
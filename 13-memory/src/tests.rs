@@ -1,9 +1,25 @@
 #![allow(dead_code)]
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
 enum Formula {
 	True, False,
+	Var(String),
+	Not(Formula),
 	And(Formula, Formula), Or(Formula, Formula),
+	Implies(Formula, Formula), Iff(Formula, Formula),
 }
+
+/// Values of variables, sorted by name.
+type Assignment = BTreeMap<String, bool>;
+
+/// Builds an assignment from `(name, value)` pairs.
+fn assignment(values: &[(&str, bool)]) -> Assignment {
+	values.iter().map(|&(name, value)| (String::from(name), value)).collect()
+}
+
 impl Formula {
 	fn resolve(&self) -> bool {
 		match self {
@@ -13,6 +29,23 @@ impl Formula {
 			&Formula::Or(left, right) => left.resolve() || right.resolve(),
 		}
 	}
+
+	/// Resolves formula with variable values from `assignment`. Returns `None` when a variable is missing.
+	fn evaluate(&self, assignment: &Assignment) -> Option<bool> {
+		None
+	}
+
+	/// Names of all variables, sorted.
+	fn variables(&self) -> BTreeSet<String> {
+		BTreeSet::new()
+	}
+}
+
+/// Writes only parentheses required for `parse()` to give same formula back.
+impl fmt::Display for Formula {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
 }
 
 fn and(left: Formula, right: Formula) -> Formula {
@@ -21,6 +54,67 @@ fn and(left: Formula, right: Formula) -> Formula {
 fn or(left: Formula, right: Formula) -> Formula {
 	Formula::True
 }
+fn not(inner: Formula) -> Formula {
+	Formula::True
+}
+fn implies(left: Formula, right: Formula) -> Formula {
+	Formula::False
+}
+fn iff(left: Formula, right: Formula) -> Formula {
+	Formula::True
+}
+/// Variable named `name`, made of letters, digits and `_`. Panics on names which wouldn't be parsed back,
+/// such as `true` or `a & b`.
+fn var(name: &str) -> Formula {
+	Formula::False
+}
+
+/// Parses a formula like `a & (b | !c)`. Operators from lowest to highest precedence: `<->`, `->`, `|`, `&` and `!`.
+fn parse(input: &str) -> Result<Formula, String> {
+	Err(String::from("not implemented"))
+}
+
+/// All `2^n` assignments of `variables`, from all `false` to all `true`. Last variable changes first.
+/// They are built one at a time, as they're needed. Panics with 64 variables or more.
+fn assignments<'a>(variables: &'a BTreeSet<String>) -> impl Iterator<Item = Assignment> + 'a {
+	vec![].into_iter()
+}
+
+/// Value of a formula for each assignment of its variables.
+struct TruthTable {
+	formula: Formula,
+	variables: Vec<String>,
+	rows: Vec<(Assignment, bool)>,
+}
+
+fn truth_table(formula: &Formula) -> TruthTable {
+	TruthTable { formula: Formula::True, variables: vec![], rows: vec![] }
+}
+
+/// Renders one column per variable, then formula column, with `T` and `F` values:
+///
+/// ```text
+/// a | b | a & b
+/// --+---+------
+/// F | F | F
+/// ```
+impl fmt::Display for TruthTable {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+/// Checks that `left` and `right` have same value for every assignment of their variables.
+/// Returns first assignment giving different values otherwise.
+fn equivalent(left: &Formula, right: &Formula) -> Result<(), Assignment> {
+	Ok(())
+}
+
+/// Checks that `formula` is `true` for every assignment of its variables.
+/// Returns first assignment making it `false` otherwise.
+fn is_tautology(formula: &Formula) -> Result<(), Assignment> {
+	Ok(())
+}
 
 mod formula_should {
 	use super::{Formula::{self, True, False}, and, or};
@@ -55,6 +149,291 @@ mod formula_should {
 	}
 }
 
+mod evaluate_should {
+	use super::{Formula::{True, False}, assignment, and, or, not, implies, iff, var};
+
+	#[test]
+	fn resolve_negation() {
+		assert!(not(False).resolve());
+		assert!(!not(True).resolve());
+	}
+
+	#[test]
+	fn resolve_implication() {
+		assert!(implies(False, False).resolve());
+		assert!(implies(False, True).resolve());
+		assert!(!implies(True, False).resolve());
+		assert!(implies(True, True).resolve());
+	}
+
+	#[test]
+	fn resolve_equivalence() {
+		assert!(iff(False, False).resolve());
+		assert!(!iff(False, True).resolve());
+		assert!(!iff(True, False).resolve());
+		assert!(iff(True, True).resolve());
+	}
+
+	#[test]
+	fn read_variables_from_assignment() {
+		let formula = and(var("a"), or(var("b"), not(var("c"))));
+		assert_eq!(Some(true), formula.evaluate(&assignment(&[("a", true), ("b", false), ("c", false)])));
+		assert_eq!(Some(false), formula.evaluate(&assignment(&[("a", true), ("b", false), ("c", true)])));
+		assert_eq!(Some(false), formula.evaluate(&assignment(&[("a", false), ("b", true), ("c", true)])));
+	}
+
+	#[test]
+	fn return_none_when_variable_is_missing() {
+		assert_eq!(None, or(True, var("a")).evaluate(&assignment(&[("b", true)])));
+		assert_eq!(Some(true), var("a").evaluate(&assignment(&[("a", true), ("unused", false)])));
+	}
+
+	#[test]
+	#[should_panic(expected = "formula must not contain variables")]
+	fn not_resolve_variables() {
+		var("a").resolve();
+	}
+}
+
+mod display_should {
+	use super::{Formula::{True, False}, and, or, not, implies, iff, var};
+
+	#[test]
+	fn write_constants_and_variables() {
+		assert_eq!("true", True.to_string());
+		assert_eq!("false", False.to_string());
+		assert_eq!("rainy_day", var("rainy_day").to_string());
+	}
+
+	#[test]
+	fn write_operators() {
+		assert_eq!("!a", not(var("a")).to_string());
+		assert_eq!("a & b", and(var("a"), var("b")).to_string());
+		assert_eq!("a | b", or(var("a"), var("b")).to_string());
+		assert_eq!("a -> b", implies(var("a"), var("b")).to_string());
+		assert_eq!("a <-> b", iff(var("a"), var("b")).to_string());
+	}
+
+	#[test]
+	fn omit_parentheses_when_precedence_is_enough() {
+		assert_eq!("a & b | c", or(and(var("a"), var("b")), var("c")).to_string());
+		assert_eq!("!a & b -> c <-> d", iff(implies(and(not(var("a")), var("b")), var("c")), var("d")).to_string());
+		assert_eq!("!!a", not(not(var("a"))).to_string());
+	}
+
+	#[test]
+	fn write_parentheses_when_precedence_is_not_enough() {
+		assert_eq!("a & (b | !c)", and(var("a"), or(var("b"), not(var("c")))).to_string());
+		assert_eq!("!(a & b)", not(and(var("a"), var("b"))).to_string());
+		assert_eq!("(a <-> b) -> c", implies(iff(var("a"), var("b")), var("c")).to_string());
+	}
+
+	#[test]
+	fn follow_grouping_direction() {
+		assert_eq!("a & b & c", and(and(var("a"), var("b")), var("c")).to_string());
+		assert_eq!("a & (b & c)", and(var("a"), and(var("b"), var("c"))).to_string());
+		assert_eq!("a -> b -> c", implies(var("a"), implies(var("b"), var("c"))).to_string());
+		assert_eq!("(a -> b) -> c", implies(implies(var("a"), var("b")), var("c")).to_string());
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_empty_variable_name() {
+		var("");
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_constant_as_variable_name() {
+		var("true");
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_operator_in_variable_name() {
+		var("a & b");
+	}
+}
+
+mod parse_should {
+	use super::{Formula::{True, False}, parse, and, or, not, implies, iff, var};
+
+	#[test]
+	fn parse_nested_formula() {
+		assert_eq!(Ok(and(var("a"), or(var("b"), not(var("c"))))), parse("a & (b | !c)"));
+	}
+
+	#[test]
+	fn parse_constants_and_names() {
+		assert_eq!(Ok(implies(True, or(var("x_1"), False))), parse("  true->x_1|false "));
+	}
+
+	#[test]
+	fn follow_precedence() {
+		assert_eq!(Ok(or(var("a"), and(var("b"), var("c")))), parse("a | b & c"));
+		assert_eq!(Ok(and(not(var("a")), var("b"))), parse("!a & b"));
+		assert_eq!(Ok(iff(var("a"), implies(var("b"), var("c")))), parse("a <-> b -> c"));
+	}
+
+	#[test]
+	fn follow_grouping_direction() {
+		assert_eq!(Ok(or(or(var("a"), var("b")), var("c"))), parse("a | b | c"));
+		assert_eq!(Ok(implies(var("a"), implies(var("b"), var("c")))), parse("a -> b -> c"));
+		assert_eq!(Ok(iff(iff(var("a"), var("b")), var("c"))), parse("a <-> b <-> c"));
+	}
+
+	#[test]
+	fn parse_displayed_formula_back() {
+		let formulas = vec![
+			and(var("a"), and(var("b"), var("c"))),
+			implies(implies(var("a"), var("b")), var("c")),
+			not(or(var("a"), not(iff(True, var("b"))))),
+			iff(and(var("p"), implies(var("q"), False)), or(var("r"), var("p"))),
+		];
+		for formula in formulas {
+			assert_eq!(Ok(formula.clone()), parse(&formula.to_string()), "{}", formula);
+		}
+	}
+
+	#[test]
+	fn report_errors() {
+		assert_eq!(Err(String::from("unexpected end of formula")), parse(""));
+		assert_eq!(Err(String::from("unexpected end of formula")), parse("a &"));
+		assert_eq!(Err(String::from("expected ')'")), parse("(a | b"));
+		assert_eq!(Err(String::from("unexpected 'b'")), parse("a b"));
+		assert_eq!(Err(String::from("unexpected ')'")), parse("a)"));
+		assert_eq!(Err(String::from("unexpected '&'")), parse("& a"));
+		assert_eq!(Err(String::from("unexpected character '#'")), parse("a # b"));
+		assert_eq!(Err(String::from("unexpected character '<'")), parse("a <- b"));
+	}
+}
+
+mod truth_table_should {
+	use super::{Assignment, Formula::{True, False}, assignment, assignments, truth_table, and, or, not, implies, var};
+
+	#[test]
+	fn enumerate_all_assignments() {
+		let variables = vec![String::from("a"), String::from("b")].into_iter().collect();
+		assert_eq!(
+			vec![
+				assignment(&[("a", false), ("b", false)]),
+				assignment(&[("a", false), ("b", true)]),
+				assignment(&[("a", true), ("b", false)]),
+				assignment(&[("a", true), ("b", true)]),
+			],
+			assignments(&variables).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn enumerate_lazily() {
+		let variables = (0..63).map(|index| format!("x{}", index)).collect();
+		assert_eq!(2, assignments(&variables).take(2).count());
+	}
+
+	#[test]
+	#[should_panic(expected = "too many variables")]
+	fn reject_64_variables() {
+		let variables = (0..64).map(|index| format!("x{}", index)).collect();
+		assignments(&variables).next();
+	}
+
+	#[test]
+	fn have_a_single_row_without_variable() {
+		let table = truth_table(&or(True, False));
+		assert!(table.variables.is_empty());
+		assert_eq!(vec![(Assignment::new(), true)], table.rows);
+	}
+
+	#[test]
+	fn sort_and_deduplicate_variables() {
+		let table = truth_table(&and(var("c"), or(var("a"), not(var("c")))));
+		assert_eq!(vec!["a", "c"], table.variables);
+		assert_eq!(4, table.rows.len());
+		assert_eq!((assignment(&[("a", true), ("c", true)]), true), table.rows[3]);
+	}
+
+	#[test]
+	fn render_table() {
+		assert_eq!(
+			"a | b | a -> b\n\
+			 --+---+-------\n\
+			 F | F | T\n\
+			 F | T | T\n\
+			 T | F | F\n\
+			 T | T | T\n",
+			truth_table(&implies(var("a"), var("b"))).to_string()
+		);
+	}
+
+	#[test]
+	fn render_table_without_variable() {
+		assert_eq!("!true\n-----\nF\n", truth_table(&not(True)).to_string());
+	}
+}
+
+mod equivalence_should {
+	use super::{Assignment, Formula::{True, False}, assignment, equivalent, is_tautology, parse, and, or, not, implies, iff, var};
+
+	#[test]
+	fn accept_de_morgan_laws() {
+		assert_eq!(Ok(()), equivalent(&not(and(var("a"), var("b"))), &or(not(var("a")), not(var("b")))));
+		assert_eq!(Ok(()), equivalent(&not(or(var("a"), var("b"))), &and(not(var("a")), not(var("b")))));
+	}
+
+	#[test]
+	fn give_counterexample_when_not_equivalent() {
+		assert_eq!(Err(assignment(&[("a", false), ("b", true)])), equivalent(&implies(var("a"), var("b")), &implies(var("b"), var("a"))));
+	}
+
+	#[test]
+	fn assign_variables_of_both_formulas() {
+		assert_eq!(Err(assignment(&[("a", false), ("b", false)])), equivalent(&var("a"), &or(var("a"), not(var("b")))));
+		assert_eq!(Ok(()), equivalent(&and(var("a"), or(var("b"), not(var("b")))), &var("a")));
+	}
+
+	#[test]
+	fn stop_at_first_counterexample() {
+		// 2^40 assignments couldn't all be checked
+		let names: Vec<String> = (0..40).map(|index| format!("x{}", index)).collect();
+		let all = names.iter().skip(1).fold(var(&names[0]), |formula, name| and(formula, var(name)));
+		let counterexample = names.iter().map(|name| (name.clone(), false)).collect();
+		assert_eq!(Err(counterexample), equivalent(&all, &not(var("x0"))));
+	}
+
+	#[test]
+	fn check_simplifications() {
+		let simplifications = vec![
+			(and(True, True), True),
+			(and(True, False), False),
+			(or(False, False), False),
+			(or(True, False), True),
+			(and(True, var("x")), var("x")),
+			(or(False, var("x")), var("x")),
+			(and(var("x"), or(var("x"), var("y"))), var("x")),
+			(or(and(var("x"), var("y")), and(var("x"), not(var("y")))), var("x")),
+			(implies(var("x"), var("y")), or(not(var("x")), var("y"))),
+			(iff(var("x"), var("y")), and(implies(var("x"), var("y")), implies(var("y"), var("x")))),
+		];
+		for (formula, simplified) in simplifications {
+			assert_eq!(Ok(()), equivalent(&formula, &simplified), "{} should be {}", formula, simplified);
+		}
+	}
+
+	#[test]
+	fn accept_tautologies() {
+		assert_eq!(Ok(()), is_tautology(&True));
+		assert_eq!(Ok(()), is_tautology(&or(var("a"), not(var("a")))));
+		assert_eq!(Ok(()), is_tautology(&parse("(a -> b) & (b -> c) -> a -> c").unwrap()));
+	}
+
+	#[test]
+	fn give_counterexample_when_not_a_tautology() {
+		assert_eq!(Err(Assignment::new()), is_tautology(&False));
+		assert_eq!(Err(assignment(&[("a", true), ("b", false)])), is_tautology(&implies(var("a"), var("b"))));
+	}
+}
+
 /// # spy
 /// Implements a very simple spy which counts application calls.
 mod spy {
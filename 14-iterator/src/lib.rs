@@ -0,0 +1,144 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 14 Iterator
+//! -----------
+//!
+//! Welcome to fourteenth step of this Rust workshop.
+//!
+//! This step focuses on iterating over data with your own types.
+//!
+//! ## Iterator trait
+//!
+//! Iterators have already been used many times (`into_iter()`, `map`, `collect`, ranges, ...). All of them implement a single trait: [`std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html). Only one method is required: `next`, which returns `Some(item)` while there are items and `None` when iteration is over.
+//!
+//! ```rust
+//! struct Steps {
+//!     current: u32,
+//!     end: u32,
+//! }
+//!
+//! impl Iterator for Steps {
+//!     type Item = u32;
+//!
+//!     fn next(&mut self) -> Option<u32> {
+//!         if self.current < self.end {
+//!             self.current += 2;
+//!             Some(self.current - 2)
+//!         } else {
+//!             None
+//!         }
+//!     }
+//! }
+//!
+//! let steps = Steps { current: 0, end: 7 };
+//! assert_eq!(vec![0, 2, 4, 6], steps.collect::<Vec<_>>());
+//! ```
+//!
+//! _Note: `Item` is an associated type (see [06-trait](../06_trait/index.html)). An iterator may also never return `None`, it's then called an infinite iterator._
+//!
+//! ## Adapters & consumers
+//!
+//! All other methods (more than 70 !) are provided by default. They fall into two categories:
+//!
+//! * _adapters_ (`map`, `filter`, `zip`, `take`, `skip`, `chain`, ...) take an iterator and return a new one.
+//! * _consumers_ (`collect`, `sum`, `count`, `fold`, `find`, `for` loop, ...) call `next` until they get what they need.
+//!
+//! Adapters are **lazy**: nothing is computed until a consumer asks for items. Thus, following code prints nothing:
+//!
+//! ```rust
+//! let numbers = vec![1, 2, 3];
+//! let _lazy = numbers.iter().map(|n| println!("{}", n));
+//! ```
+//!
+//! And this one only computes three squares even if range is infinite:
+//!
+//! ```rust
+//! let squares: Vec<u64> = (1..).map(|n| n * n).take(3).collect();
+//! assert_eq!(vec![1, 4, 9], squares);
+//! ```
+//!
+//! ## Writing an adapter
+//!
+//! An adapter is just a struct wrapping another iterator (generally named `iter`) and implementing `Iterator` itself:
+//!
+//! ```rust
+//! struct Doubled<I> {
+//!     iter: I,
+//! }
+//!
+//! impl<I: Iterator<Item=u32>> Iterator for Doubled<I> {
+//!     type Item = u32;
+//!
+//!     fn next(&mut self) -> Option<u32> {
+//!         self.iter.next().map(|n| n * 2)
+//!     }
+//! }
+//! ```
+//!
+//! To make it available as a method on every iterator, an _extension trait_ is implemented for any `Iterator` (see [08-generics](../08_generics/index.html)):
+//!
+//! ```rust
+//! # struct Doubled<I> { iter: I }
+//! # impl<I: Iterator<Item=u32>> Iterator for Doubled<I> {
+//! #     type Item = u32;
+//! #     fn next(&mut self) -> Option<u32> { self.iter.next().map(|n| n * 2) }
+//! # }
+//! trait DoubledExt: Sized {
+//!     fn doubled(self) -> Doubled<Self>;
+//! }
+//!
+//! impl<I: Iterator<Item=u32>> DoubledExt for I {
+//!     fn doubled(self) -> Doubled<Self> {
+//!         Doubled { iter: self }
+//!     }
+//! }
+//!
+//! assert_eq!(vec![2, 4, 6], vec![1, 2, 3].into_iter().doubled().collect::<Vec<_>>());
+//! ```
+//!
+//! ## IntoIterator
+//!
+//! `for` loop doesn't require an `Iterator` but any type implementing [`std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html). That's why a `Vec` can be iterated in three ways:
+//!
+//! ```rust
+//! let mut vec = vec![1, 2, 3];
+//!
+//! for item in &vec {      // item: &i32
+//!     println!("{}", item);
+//! }
+//!
+//! for item in &mut vec {  // item: &mut i32
+//!     *item += 1;
+//! }
+//!
+//! for item in vec {       // item: i32, vec is consumed
+//!     println!("{}", item);
+//! }
+//! ```
+//!
+//! Each loop uses a different implementation: `IntoIterator for &Vec<T>`, `IntoIterator for &mut Vec<T>` and `IntoIterator for Vec<T>`. Implementations for references require to name the lifetime of the borrow (see [05-lifetime](../05_lifetime/index.html)):
+//!
+//! ```rust
+//! struct Bag { items: Vec<String> }
+//!
+//! impl<'a> IntoIterator for &'a Bag {
+//!     type Item = &'a String;
+//!     type IntoIter = std::slice::Iter<'a, String>;
+//!
+//!     fn into_iter(self) -> Self::IntoIter {
+//!         self.items.iter()
+//!     }
+//! }
+//!
+//! let bag = Bag { items: vec![String::from("foo"), String::from("bar")] };
+//! for item in &bag {
+//!     println!("{}", item);
+//! }
+//! ```
+//!
+//! By convention, collections also provide `iter()` and `iter_mut()` methods returning same iterators.
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,154 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 15 Module
+//! ---------
+//!
+//! Welcome to fifteenth step of this Rust workshop.
+//!
+//! This step focuses on organizing code into modules and controlling what is visible from outside.
+//!
+//! ## Module tree
+//!
+//! Each crate is a tree of modules whose root is `lib.rs` (or `main.rs` for binaries). Modules are declared with `mod` keyword and can be nested:
+//!
+//! ```rust
+//! mod network {
+//!     mod server {
+//!         fn start() {}
+//!     }
+//!
+//!     mod client {
+//!         fn connect() {}
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Most of the time, module content is stored in a separate file. Then only declaration remains and its body is searched into a file named after module:
+//!
+//! ```text
+//! src/
+//! ├── lib.rs          mod network;
+//! ├── network.rs      mod server; mod client;
+//! └── network/
+//!     ├── server.rs   fn start() {}
+//!     └── client.rs   fn connect() {}
+//! ```
+//!
+//! _Note: `network.rs` can also be named `network/mod.rs`, that's the historical layout you will still find in many projects._
+//!
+//! ## Visibility
+//!
+//! Everything is **private** by default: an item is only visible from module where it is declared and its descendants. `pub` keyword makes it visible from parent module (and so on if parents are also visible).
+//!
+//! ```compile_fail
+//! mod network {
+//!     fn start() {}
+//! }
+//!
+//! network::start(); // Compilation error: function `start` is private
+//! ```
+//!
+//! ```rust
+//! mod network {
+//!     pub fn start() {}
+//! }
+//!
+//! network::start(); // Ok
+//! ```
+//!
+//! Visibility is also restricted per struct field. A struct with private fields can't be built outside of its module, that's why constructors (`new`) are so common:
+//!
+//! ```compile_fail
+//! mod user {
+//!     pub struct User {
+//!         pub name: String,
+//!         password: String,
+//!     }
+//! }
+//!
+//! let user = user::User { name: String::from("root"), password: String::from("secret") }; // Compilation error: field `password` is private
+//! ```
+//!
+//! _Note: on the contrary, `enum` variants are always as visible as their `enum`._
+//!
+//! `pub` can be restricted to a given scope:
+//!
+//! * `pub(crate)` makes item visible within current crate only,
+//! * `pub(super)` makes item visible from parent module only,
+//! * `pub(in path)` makes item visible within given ancestor module.
+//!
+//! ```rust
+//! mod network {
+//!     pub mod server {
+//!         pub(super) fn bind() {}
+//!         pub(crate) fn start() {
+//!             bind();
+//!         }
+//!     }
+//!
+//!     pub fn restart() {
+//!         server::bind();  // Ok: `network` is parent of `server`
+//!     }
+//! }
+//!
+//! network::server::start(); // Ok: same crate
+//! network::restart();
+//! // Compilation error
+//! // network::server::bind();
+//! ```
+//!
+//! Always choose the most restrictive visibility: anything that is `pub` in a library becomes part of its API and can't be changed without breaking users.
+//!
+//! ## Paths
+//!
+//! Items are referred by their _path_ in module tree, where segments are separated by `::`. A path may start from:
+//!
+//! * current module: `self::server::start()`,
+//! * parent module: `super::restart()`,
+//! * crate root: `::network::restart()` or `crate::network::restart()`,
+//! * another crate: `std::collections::HashMap`.
+//!
+//! Paths starting from a name are relative to current module, **except** into `use` declarations where they are relative to crate root (it has changed in Rust 2018 edition, but the workshop uses 2015 one).
+//!
+//! ```rust
+//! mod network {
+//!     pub mod server {
+//!         pub fn start() {}
+//!     }
+//!
+//!     pub mod client {
+//!         use network::server;    // From crate root
+//!         use super::server::start; // From current module
+//!
+//!         pub fn connect() {
+//!             server::start();
+//!             start();
+//!         }
+//!     }
+//! }
+//! # fn main() { network::client::connect(); }
+//! ```
+//!
+//! ## Re-export
+//!
+//! `use` only imports a name into current module. But a `pub use` also makes it part of current module API. It's commonly used to expose a flat API while keeping a deep module tree:
+//!
+//! ```rust
+//! mod network {
+//!     mod server {
+//!         pub struct Server;
+//!     }
+//!
+//!     pub use self::server::Server;
+//! }
+//!
+//! let server = network::Server; // `server` module is private but `Server` is still reachable
+//! ```
+//!
+//! That's exactly what `std` does: [`std::collections::HashMap`](https://doc.rust-lang.org/std/collections/struct.HashMap.html) is defined into a private `hash` module.
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,137 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Nothing has to be implemented, only visibility and paths have to be fixed. Always choose the most restrictive visibility.
+//!
+
+mod geometry {
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    impl Point {
+        fn new(x: i64, y: i64) -> Self {
+            Point { x, y }
+        }
+
+        fn manhattan(&self, other: &Point) -> i64 {
+            (self.x - other.x).abs() + (self.y - other.y).abs()
+        }
+    }
+
+    mod shapes {
+        use Point;
+
+        struct Rectangle {
+            top_left: Point,
+            bottom_right: Point,
+        }
+
+        impl Rectangle {
+            fn new(top_left: Point, bottom_right: Point) -> Self {
+                Rectangle { top_left, bottom_right }
+            }
+
+            fn area(&self) -> i64 {
+                (self.bottom_right.x - self.top_left.x) * (self.bottom_right.y - self.top_left.y)
+            }
+        }
+    }
+}
+
+mod geometry_should {
+    use super::geometry::Point;
+    use super::geometry::shapes::Rectangle;
+
+    #[test]
+    fn give_access_to_point_coordinates() {
+        let point = Point::new(4, 2);
+        assert_eq!(4, point.x);
+        assert_eq!(2, point.y);
+    }
+
+    #[test]
+    fn compute_manhattan_distance_between_points() {
+        assert_eq!(7, Point::new(1, 2).manhattan(&Point { x: 4, y: 6 }));
+    }
+
+    #[test]
+    fn compute_rectangle_area() {
+        let rectangle = Rectangle::new(Point::new(1, 2), Point::new(4, 6));
+        assert_eq!(12, rectangle.area());
+    }
+}
+
+mod counter {
+    struct Counter {
+        value: u32,
+    }
+
+    impl Counter {
+        fn new() -> Self {
+            Counter { value: 0 }
+        }
+
+        fn value(&self) -> u32 {
+            self.value
+        }
+
+        fn tick(&mut self) {
+            inner::increment(&mut self.value);
+        }
+    }
+
+    mod inner {
+        fn increment(value: &mut u32) {
+            *value += 1;
+        }
+    }
+}
+
+mod counter_should {
+    use super::counter::Counter;
+
+    #[test]
+    fn start_at_0() {
+        assert_eq!(0, Counter::new().value());
+    }
+
+    #[test]
+    fn return_2_after_two_ticks() {
+        let mut counter = Counter::new();
+        counter.tick();
+        counter.tick();
+        assert_eq!(2, counter.value());
+    }
+}
+
+mod shop;
+
+mod shop_should {
+    use super::shop::{Cart, Product};
+
+    #[test]
+    fn create_product() {
+        let product = Product::new("Rust in Action", 40);
+        assert_eq!("Rust in Action", product.name());
+        assert_eq!(40, product.price());
+    }
+
+    #[test]
+    fn sum_prices_without_discount_below_100() {
+        let mut cart = Cart::new();
+        cart.add(Product::new("Programming Rust", 50));
+        cart.add(Product::new("Rust in Action", 40));
+        assert_eq!(90, cart.total());
+    }
+
+    #[test]
+    fn apply_discount_above_100() {
+        let mut cart = Cart::new();
+        cart.add(Product::new("Programming Rust", 50));
+        cart.add(Product::new("Rust in Action", 40));
+        cart.add(Product::new("The Rust Programming Language", 30));
+        assert_eq!(108, cart.total());
+    }
+}
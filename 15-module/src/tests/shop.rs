@@ -0,0 +1,7 @@
+//! Online shop split into many files, as real projects do.
+
+mod catalog;
+mod cart;
+mod pricing;
+
+//TODO: make `Product` and `Cart` available as `shop::Product` and `shop::Cart`
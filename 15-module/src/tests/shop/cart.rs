@@ -0,0 +1,21 @@
+use catalog::Product;
+use pricing::discount;
+
+struct Cart {
+    products: Vec<Product>,
+}
+
+impl Cart {
+    fn new() -> Self {
+        Cart { products: vec![] }
+    }
+
+    fn add(&mut self, product: Product) {
+        self.products.push(product);
+    }
+
+    fn total(&self) -> u64 {
+        let total = self.products.iter().map(|product| product.price()).sum();
+        discount(total)
+    }
+}
@@ -0,0 +1,18 @@
+struct Product {
+    name: String,
+    price: u64,
+}
+
+impl Product {
+    fn new(name: &str, price: u64) -> Self {
+        Product { name: String::from(name), price }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn price(&self) -> u64 {
+        self.price
+    }
+}
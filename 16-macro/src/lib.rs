@@ -0,0 +1,148 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 16 Macro
+//! --------
+//!
+//! Welcome to sixteenth step of this Rust workshop.
+//!
+//! This step focuses on writing code that writes code: declarative macros.
+//!
+//! ## What is a macro ?
+//!
+//! Since first step, you have used many macros: `vec!`, `format!`, `println!`, `assert_eq!`, `panic!`, ... They are recognizable by their trailing `!`. Unlike functions, they are expanded at compile time into regular code. That's why they can accept a variable number of arguments or check a format string at compile time.
+//!
+//! For example, `vec![1, 2, 3]` roughly expands to:
+//!
+//! ```rust
+//! let v = {
+//!     let mut v = Vec::new();
+//!     v.push(1);
+//!     v.push(2);
+//!     v.push(3);
+//!     v
+//! };
+//! ```
+//!
+//! ## Syntax
+//!
+//! Macros are declared with `macro_rules!` and consist in a list of _rules_ `(matcher) => { expansion };`. Rules are tried in order and first matching one is expanded.
+//!
+//! ```rust
+//! macro_rules! greet {
+//!     () => { String::from("Hello world !") };
+//!     ($name:expr) => { format!("Hello {} !", $name) };
+//! }
+//!
+//! assert_eq!("Hello world !", greet!());
+//! assert_eq!("Hello Ferris !", greet!("Ferris"));
+//! ```
+//!
+//! Matcher captures _fragments_ into `$name:kind` variables. Most common kinds are:
+//!
+//! * `expr`: an expression (`1 + 2`, `foo()`, `"bar"`, ...)
+//! * `ident`: an identifier (`foo`, `Bar`, ...)
+//! * `ty`: a type (`u8`, `Vec<String>`, ...)
+//! * `literal`: a literal (`42`, `"foo"`, ...)
+//! * `tt`: a single token tree (any token or group between `()`, `[]` or `{}`)
+//!
+//! Other tokens of matcher have to be present as is. It allows to define your own syntax:
+//!
+//! ```rust
+//! macro_rules! square {
+//!     ($value:expr => squared) => { $value * $value };
+//! }
+//!
+//! assert_eq!(16, square!(4 => squared));
+//! ```
+//!
+//! _Note: a macro can be invoked with `()`, `[]` or `{}`. By convention, `[]` is used for collections and `{}` for items or blocks._
+//!
+//! ## Repetition
+//!
+//! A fragment can be repeated with `$(...) sep rep` where `sep` is an optional separator (often `,` or `;`) and `rep` is either `*` (zero or more), `+` (one or more) or `?` (zero or one). Same syntax is used into expansion to repeat code for each captured fragment:
+//!
+//! ```rust
+//! macro_rules! sum {
+//!     ($($value:expr),*) => { 0 $(+ $value)* };
+//! }
+//!
+//! assert_eq!(0, sum!());
+//! assert_eq!(6, sum!(1, 2, 3));
+//! ```
+//!
+//! ## Recursion
+//!
+//! A macro may call itself, generally to consume its input one piece at a time:
+//!
+//! ```rust
+//! macro_rules! reverse {
+//!     ([] $($reversed:expr),*) => { vec![$($reversed),*] };
+//!     ([$head:expr $(, $tail:expr)*] $($reversed:expr),*) => { reverse!([$($tail),*] $head $(, $reversed)*) };
+//! }
+//!
+//! assert_eq!(vec![3, 2, 1], reverse!([1, 2, 3]));
+//! ```
+//!
+//! ## Hygiene
+//!
+//! Variables declared into a macro can't clash with caller ones. Following macro doesn't change caller `value`:
+//!
+//! ```rust
+//! macro_rules! forty_two {
+//!     () => { let value = 42; };
+//! }
+//!
+//! let value = 0;
+//! forty_two!();
+//! assert_eq!(0, value);
+//! ```
+//!
+//! However, that isn't true for items (`struct`, `fn`, ...) which can be freely generated:
+//!
+//! ```rust
+//! macro_rules! unit_struct {
+//!     ($name:ident) => {
+//!         #[derive(Debug)]
+//!         struct $name;
+//!     };
+//! }
+//!
+//! unit_struct!(Foo);
+//! println!("{:?}", Foo);
+//! ```
+//!
+//! Be also careful that expressions captured by a macro are evaluated each time they are expanded. Bind them to a variable if they must be evaluated only once.
+//!
+//! ## Misuse
+//!
+//! When no rule matches, compilation fails. That's a nice way to reject invalid input at compile time:
+//!
+//! ```compile_fail
+//! macro_rules! pair {
+//!     ($left:expr, $right:expr) => { ($left, $right) };
+//! }
+//!
+//! pair!(1, 2, 3); // Compilation error: no rules expected the token `,`
+//! ```
+//!
+//! ## Scope
+//!
+//! Unlike other items, macros can only be used **after** their declaration. To use macros declared into a child module, annotate it with `#[macro_use]`. To make it available to other crates, annotate macro with `#[macro_export]` (and use `$crate` to refer to items of your crate from expansion).
+//!
+//! ```rust
+//! #[macro_use]
+//! mod macros {
+//!     macro_rules! answer {
+//!         () => { 42 };
+//!     }
+//! }
+//!
+//! assert_eq!(42, answer!());
+//! ```
+//!
+//! Get more info in [The Little Book of Rust Macros](https://danielkeep.github.io/tlborm/book/).
+
+#[cfg(test)]
+mod tests;
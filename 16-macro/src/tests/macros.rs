@@ -0,0 +1,36 @@
+/// Builds a `HashMap` from `key => value` pairs.
+///
+/// For example: `hashmap!{ "one" => 1, "two" => 2 }`
+///
+macro_rules! hashmap {
+    () => { ::std::collections::HashMap::new() };
+    //TODO: support `key => value` pairs
+}
+
+/// Counts tokens given as input.
+///
+/// For example: `count!(a b c) = 3`
+///
+macro_rules! count {
+    () => { 0usize };
+    //TODO: count remaining tokens recursively
+}
+
+/// Returns greatest of all given expressions.
+///
+/// For example: `max!(1, 5, 3) = 5`
+///
+macro_rules! max {
+    ($value:expr) => { $value };
+    //TODO: support many expressions recursively
+}
+
+/// Generates a struct with getters and its fluent builder (see 04-ownership).
+///
+/// For example: `builder!(CarBuilder => Car { brand: String, model: String });`
+///
+macro_rules! builder {
+    ($builder:ident => $target:ident { $($field:ident: $type:ty),* }) => {
+        //TODO: generate target struct with its getters and builder
+    };
+}
@@ -0,0 +1,128 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 17 Shared state
+//! ---------------
+//!
+//! Welcome to seventeenth step of this Rust workshop.
+//!
+//! This step focuses on sharing data between threads. It sits between [12-parallelism](../12_parallelism/index.html) (threads and channels) and [13-memory](../13_memory/index.html) (`Rc` and `RefCell` in a single thread).
+//!
+//! ## Send & Sync
+//!
+//! Rust prevents data races at compile time thanks to two marker traits, automatically implemented by compiler:
+//!
+//! * `Send`: value can be **moved** to another thread,
+//! * `Sync`: value can be **shared** (`&T`) between threads.
+//!
+//! Most types are both, but not `Rc` (its counter isn't updated atomically) nor `Cell`/`RefCell` (their borrow checks aren't thread-safe). Thus, following code doesn't compile:
+//!
+//! ```compile_fail
+//! use std::rc::Rc;
+//! use std::thread;
+//!
+//! let shared = Rc::new(42);
+//! let cloned = shared.clone();
+//! thread::spawn(move || println!("{}", cloned)); // Compilation error: `Rc<i32>` cannot be sent between threads safely
+//! ```
+//!
+//! ## Arc
+//!
+//! [`std::sync::Arc`](https://doc.rust-lang.org/std/sync/struct.Arc.html) (_Atomically Reference Counted_) is the thread-safe equivalent of `Rc`. It only provides read-only access:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! let shared = Arc::new(vec![1, 2, 3]);
+//! let handles: Vec<_> = (0..3).map(|index| {
+//!     let shared = shared.clone();
+//!     thread::spawn(move || shared[index] * 2)
+//! }).collect();
+//!
+//! let results: Vec<i32> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+//! assert_eq!(vec![2, 4, 6], results);
+//! ```
+//!
+//! ## Mutex
+//!
+//! To mutate shared data, [`std::sync::Mutex`](https://doc.rust-lang.org/std/sync/struct.Mutex.html) is the thread-safe equivalent of `RefCell`. Instead of panicking, `lock()` blocks until no other thread holds the lock. It returns a _guard_ which gives access to data and releases lock when dropped.
+//!
+//! ```rust
+//! use std::sync::{Arc, Mutex};
+//! use std::thread;
+//!
+//! let counter = Arc::new(Mutex::new(0));
+//! let handles: Vec<_> = (0..10).map(|_| {
+//!     let counter = counter.clone();
+//!     thread::spawn(move || {
+//!         let mut guard = counter.lock().unwrap();
+//!         *guard += 1;
+//!     }) // guard is dropped, lock is released
+//! }).collect();
+//!
+//! for handle in handles {
+//!     handle.join().unwrap();
+//! }
+//! assert_eq!(10, *counter.lock().unwrap());
+//! ```
+//!
+//! _Note: `lock()` returns a `Result` because a mutex is **poisoned** when a thread panics while holding it._
+//!
+//! ## RwLock
+//!
+//! When data is mostly read, [`std::sync::RwLock`](https://doc.rust-lang.org/std/sync/struct.RwLock.html) allows many readers at the same time, but only one writer (like borrowing rules):
+//!
+//! ```rust
+//! use std::sync::RwLock;
+//!
+//! let lock = RwLock::new(String::from("rust"));
+//! {
+//!     let read0 = lock.read().unwrap();
+//!     let read1 = lock.read().unwrap(); // Ok
+//!     println!("{} {}", *read0, *read1);
+//! }
+//! lock.write().unwrap().push_str("acean");
+//! ```
+//!
+//! ## Atomics
+//!
+//! For simple values (integers and booleans), [`std::sync::atomic`](https://doc.rust-lang.org/std/sync/atomic/index.html) types are the thread-safe equivalent of `Cell`. They are updated without any lock, thanks to dedicated CPU instructions:
+//!
+//! ```rust
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! let counter = AtomicUsize::new(0);
+//! counter.fetch_add(1, Ordering::Relaxed);
+//! assert_eq!(1, counter.load(Ordering::Relaxed));
+//! ```
+//!
+//! `Ordering` tells how other memory accesses may be reordered around the atomic one. `Relaxed` is enough for independent counters ; when in doubt, use `SeqCst` which is the strongest one.
+//!
+//! ## Scoped threads
+//!
+//! `thread::spawn` requires a `'static` closure, because spawned thread may outlive current function. [`std::thread::scope`](https://doc.rust-lang.org/std/thread/fn.scope.html) guarantees all threads spawned into it are joined before it returns. So they can borrow local data, no `Arc` required:
+//!
+//! ```rust
+//! use std::thread;
+//!
+//! let numbers = vec![1, 2, 3, 4];
+//! let (left, right) = numbers.split_at(2);
+//!
+//! let sum = thread::scope(|scope| {
+//!     let left = scope.spawn(|| left.iter().sum::<i32>());
+//!     let right = scope.spawn(|| right.iter().sum::<i32>());
+//!     left.join().unwrap() + right.join().unwrap()
+//! });
+//! assert_eq!(10, sum);
+//! ```
+//!
+//! ## Deadlock
+//!
+//! Compiler prevents data races, but not **deadlocks**. When a thread holds lock `A` and waits for lock `B`, while another one holds `B` and waits for `A`, both wait forever. Most common way to avoid it is to always take locks in the same order (e.g. sorted by an identifier).
+//!
+//! Get more info: [rust-book/shared-state](https://doc.rust-lang.org/book/ch16-03-shared-state.html).
+
+#[cfg(test)]
+mod tests;
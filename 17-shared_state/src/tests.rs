@@ -0,0 +1,371 @@
+#![allow(dead_code)]
+
+use std::thread;
+
+/// Runs `f` into `count` threads and waits for all of them.
+fn run_in_threads<F>(count: usize, f: F) where
+    F: Fn(usize) + Send + Sync + 'static {
+    let f = std::sync::Arc::new(f);
+    let handles: Vec<_> = (0..count).map(|index| {
+        let f = f.clone();
+        thread::spawn(move || f(index))
+    }).collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+/// # spy
+/// Implements a very simple spy which counts application calls, even from many threads (see 13-memory).
+///
+/// _Note: this is 13-memory solution, make it thread-safe._
+///
+mod spy {
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    #[derive(Default,Clone,Copy)]
+    pub struct Stats {
+        hello: u32,
+        goodbye: u32,
+    }
+
+    #[derive(Clone)]
+    pub struct Api {
+        stats: Rc<RefCell<Stats>>,
+    }
+
+    impl Api {
+        pub fn new() -> Self {
+            Api { stats: Rc::default(), }
+        }
+
+        pub fn stats(&self) -> Stats {
+            *self.stats.borrow()
+        }
+
+        pub fn hello(&self) {
+            self.stats.borrow_mut().hello += 1;
+        }
+
+        pub fn goodbye(&self) {
+            self.stats.borrow_mut().goodbye += 1;
+        }
+    }
+
+    impl Stats {
+        pub fn hello(&self) -> u32 {
+            self.hello
+        }
+
+        pub fn goodbye(&self) -> u32 {
+            self.goodbye
+        }
+    }
+
+    mod should {
+        use super::Api;
+        use super::super::run_in_threads;
+
+        #[test]
+        fn register_hello_1_goodbye_1_after_hello_goodbye() {
+            let api = Api::new();
+            api.hello();
+            api.goodbye();
+
+            let stats = api.stats();
+            assert_eq!(1, stats.hello(), "hello");
+            assert_eq!(1, stats.goodbye(), "goodbye");
+        }
+
+        #[test]
+        fn share_stats_between_clones() {
+            let api = Api::new();
+            api.clone().hello();
+
+            assert_eq!(1, api.stats().hello());
+        }
+
+        #[test]
+        fn register_hello_8000_goodbye_4000_from_8_threads() {
+            let api = Api::new();
+            let shared = api.clone();
+            run_in_threads(8, move |index| {
+                for _ in 0..1000 {
+                    shared.hello();
+                    if index % 2 == 0 {
+                        shared.goodbye();
+                    }
+                }
+            });
+
+            let stats = api.stats();
+            assert_eq!(8000, stats.hello(), "hello");
+            assert_eq!(4000, stats.goodbye(), "goodbye");
+        }
+    }
+}
+
+/// # atomic_spy
+/// Same spy, but without any lock.
+mod atomic_spy {
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct Counters {
+        hello: u32,
+        goodbye: u32,
+    }
+
+    #[derive(Clone)]
+    pub struct Api {
+        counters: Arc<Counters>,
+    }
+
+    impl Api {
+        pub fn new() -> Self {
+            Api { counters: Arc::default(), }
+        }
+
+        pub fn hello(&self) {
+            //TODO: use atomics
+        }
+
+        pub fn goodbye(&self) {
+            //TODO: use atomics
+        }
+
+        pub fn hello_count(&self) -> u32 {
+            self.counters.hello
+        }
+
+        pub fn goodbye_count(&self) -> u32 {
+            self.counters.goodbye
+        }
+    }
+
+    mod should {
+        use super::Api;
+        use super::super::run_in_threads;
+
+        #[test]
+        fn register_hello_0_goodbye_0_after_init() {
+            let api = Api::new();
+            assert_eq!(0, api.hello_count(), "hello");
+            assert_eq!(0, api.goodbye_count(), "goodbye");
+        }
+
+        #[test]
+        fn register_hello_8000_goodbye_4000_from_8_threads() {
+            let api = Api::new();
+            let shared = api.clone();
+            run_in_threads(8, move |index| {
+                for _ in 0..1000 {
+                    shared.hello();
+                    if index % 2 == 1 {
+                        shared.goodbye();
+                    }
+                }
+            });
+
+            assert_eq!(8000, api.hello_count(), "hello");
+            assert_eq!(4000, api.goodbye_count(), "goodbye");
+        }
+    }
+}
+
+/// # settings
+/// Settings store read by many threads and seldom updated.
+mod settings {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    pub struct Settings {
+        values: Arc<HashMap<String, String>>,
+    }
+
+    impl Settings {
+        pub fn new() -> Self {
+            Settings { values: Arc::default(), }
+        }
+
+        pub fn get(&self, key: &str) -> Option<String> {
+            self.values.get(key).cloned()
+        }
+
+        pub fn set(&self, key: &str, value: &str) {
+            //TODO: use a RwLock
+        }
+    }
+
+    mod should {
+        use super::Settings;
+        use super::super::run_in_threads;
+
+        #[test]
+        fn return_none_when_missing() {
+            assert_eq!(None, Settings::new().get("missing"));
+        }
+
+        #[test]
+        fn return_value_after_set() {
+            let settings = Settings::new();
+            settings.set("lang", "rust");
+            assert_eq!(Some(String::from("rust")), settings.get("lang"));
+        }
+
+        #[test]
+        fn support_concurrent_readers_and_writers() {
+            let settings = Settings::new();
+            settings.set("lang", "rust");
+            let shared = settings.clone();
+            run_in_threads(16, move |index| {
+                if index == 0 {
+                    shared.set("edition", "2015");
+                }
+                for _ in 0..1000 {
+                    assert_eq!(Some(String::from("rust")), shared.get("lang"));
+                }
+            });
+
+            assert_eq!(Some(String::from("2015")), settings.get("edition"));
+        }
+    }
+}
+
+/// Sums `numbers` by splitting them into `threads` chunks, each one summed into its own thread.
+///
+/// _Note: `numbers` is borrowed, so threads must be scoped. `0` threads is handled as `1`._
+///
+fn parallel_sum(numbers: &[u64], threads: usize) -> u64 {
+    //TODO: use scoped threads
+    0
+}
+
+mod parallel_sum_should {
+    use super::*;
+
+    #[test]
+    fn return_0_when_empty() {
+        assert_eq!(0, parallel_sum(&[], 4));
+    }
+
+    #[test]
+    fn return_15_when_1_to_5_with_2_threads() {
+        assert_eq!(15, parallel_sum(&[1, 2, 3, 4, 5], 2));
+    }
+
+    #[test]
+    fn return_same_as_sequential_sum() {
+        let numbers: Vec<u64> = (1..=100_000).collect();
+        assert_eq!(numbers.iter().sum::<u64>(), parallel_sum(&numbers, 8));
+    }
+
+    #[test]
+    fn support_more_threads_than_numbers() {
+        assert_eq!(6, parallel_sum(&[1, 2, 3], 16));
+    }
+
+    #[test]
+    fn support_zero_thread() {
+        assert_eq!(6, parallel_sum(&[1, 2, 3], 0));
+    }
+}
+
+/// # bank
+/// Accounts which can transfer money to each other, from many threads, without deadlock.
+mod bank {
+    use std::sync::Mutex;
+
+    pub struct Account {
+        id: u32,
+        balance: Mutex<i64>,
+    }
+
+    impl Account {
+        pub fn new(id: u32, balance: i64) -> Self {
+            Account { id, balance: Mutex::new(balance) }
+        }
+
+        pub fn balance(&self) -> i64 {
+            *self.balance.lock().unwrap()
+        }
+    }
+
+    /// Moves `amount` from `from` to `to`.
+    ///
+    /// _Note: it works fine in a single thread, but may deadlock when two threads transfer in opposite directions._
+    ///
+    pub fn transfer(from: &Account, to: &Account, amount: i64) {
+        let mut from_balance = from.balance.lock().unwrap();
+        let mut to_balance = to.balance.lock().unwrap();
+        *from_balance -= amount;
+        *to_balance += amount;
+    }
+
+    mod should {
+        use super::{Account, transfer};
+        use std::sync::Arc;
+        use std::sync::mpsc::channel;
+        use std::thread;
+        use std::time::Duration;
+
+        #[test]
+        fn move_money_between_accounts() {
+            let alice = Account::new(1, 100);
+            let bob = Account::new(2, 50);
+
+            transfer(&alice, &bob, 30);
+            transfer(&bob, &alice, 10);
+
+            assert_eq!(80, alice.balance());
+            assert_eq!(70, bob.balance());
+        }
+
+        #[test]
+        fn not_deadlock_when_transferring_both_ways() {
+            let alice = Arc::new(Account::new(1, 1000));
+            let bob = Arc::new(Account::new(2, 1000));
+
+            let (done, finished) = channel();
+            let handles: Vec<_> = (0..8).map(|index| {
+                let alice = alice.clone();
+                let bob = bob.clone();
+                thread::spawn(move || {
+                    for _ in 0..10_000 {
+                        if index % 2 == 0 {
+                            transfer(&alice, &bob, 1);
+                        } else {
+                            transfer(&bob, &alice, 1);
+                        }
+                    }
+                })
+            }).collect();
+            thread::spawn(move || {
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+                done.send(()).unwrap();
+            });
+
+            finished.recv_timeout(Duration::from_secs(10)).expect("deadlock");
+            assert_eq!(2000, alice.balance() + bob.balance());
+        }
+
+        #[test]
+        fn not_deadlock_when_transferring_to_same_account() {
+            let alice = Arc::new(Account::new(1, 100));
+
+            let (done, finished) = channel();
+            let account = alice.clone();
+            thread::spawn(move || {
+                transfer(&account, &account, 30);
+                done.send(()).unwrap();
+            });
+
+            finished.recv_timeout(Duration::from_secs(10)).expect("deadlock");
+            assert_eq!(100, alice.balance());
+        }
+    }
+}
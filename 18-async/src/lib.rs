@@ -0,0 +1,139 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 18 Async
+//! --------
+//!
+//! Welcome to eighteenth step of this Rust workshop.
+//!
+//! This step focuses on asynchronous programming with `async`/`.await`, and on what happens behind the scene.
+//!
+//! _Note: `async` and `await` are keywords since Rust 2018 edition, so this crate uses it (see `edition` into its `Cargo.toml`)._
+//!
+//! ## Future
+//!
+//! Threads (see [12-parallelism](../12_parallelism/index.html)) are expensive: each one has its own stack and switching between them is handled by operating system. Asynchronous programming allows many tasks to run on few threads, by making tasks give control back while they are waiting (for a timer, a network packet, ...).
+//!
+//! An asynchronous computation is represented by [`std::future::Future`](https://doc.rust-lang.org/std/future/trait.Future.html) trait:
+//!
+//! ```rust
+//! # use std::pin::Pin;
+//! # use std::task::{Context, Poll};
+//! trait Future {
+//!     type Output;
+//!     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+//! }
+//! ```
+//!
+//! `poll` is called to make progress. It returns `Poll::Ready(output)` when computation is complete, and `Poll::Pending` otherwise. Futures are **lazy**: nothing happens until someone polls them.
+//!
+//! _Note: [`Pin`](https://doc.rust-lang.org/std/pin/index.html) guarantees future won't be moved in memory anymore. Don't bother about it and use `Box::pin(future)` when you need to poll a future by yourself._
+//!
+//! ## Waker
+//!
+//! When returning `Pending`, future must arrange to be polled again when it can make progress. [`Context`](https://doc.rust-lang.org/std/task/struct.Context.html) gives access to a [`Waker`](https://doc.rust-lang.org/std/task/struct.Waker.html) ; calling `wake()` on it tells whoever is polling that future is worth polling again.
+//!
+//! ```rust
+//! use std::future::Future;
+//! use std::pin::Pin;
+//! use std::task::{Context, Poll};
+//!
+//! /// Pending until polled `remaining` times
+//! struct Countdown { remaining: u32 }
+//!
+//! impl Future for Countdown {
+//!     type Output = &'static str;
+//!
+//!     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<&'static str> {
+//!         if self.remaining == 0 {
+//!             Poll::Ready("liftoff")
+//!         } else {
+//!             self.remaining -= 1;
+//!             cx.waker().wake_by_ref(); // Ask to be polled again
+//!             Poll::Pending
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! A waker can be created from any `Arc` of a type implementing [`std::task::Wake`](https://doc.rust-lang.org/std/task/trait.Wake.html):
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//! use std::task::{Wake, Waker};
+//!
+//! struct Flag(AtomicBool);
+//!
+//! impl Wake for Flag {
+//!     fn wake(self: Arc<Self>) {
+//!         self.0.store(true, Ordering::SeqCst);
+//!     }
+//! }
+//!
+//! let flag = Arc::new(Flag(AtomicBool::new(false)));
+//! let waker = Waker::from(flag.clone());
+//! waker.wake();
+//! assert!(flag.0.load(Ordering::SeqCst));
+//! ```
+//!
+//! _Note: waker may be called from any thread, so it must be `Send + Sync` (see [17-shared_state](../17_shared_state/index.html))._
+//!
+//! ## Async & await
+//!
+//! Writing futures by hand is tedious. `async fn` (and `async` blocks) are turned by compiler into an anonymous type implementing `Future`. Into them, `.await` polls another future and gives control back while it's pending:
+//!
+//! ```rust
+//! async fn fetch_answer() -> u32 {
+//!     42
+//! }
+//!
+//! async fn display_answer() -> String {
+//!     let answer = fetch_answer().await;
+//!     format!("The answer is {}", answer)
+//! }
+//!
+//! let future = display_answer(); // Nothing has been executed yet !
+//! ```
+//!
+//! Each `.await` is a point where function may be suspended: compiler generates a state machine storing local variables alive across these points.
+//!
+//! ## Executor
+//!
+//! Unlike other languages, Rust standard library provides no _runtime_ to poll futures: it's up to an **executor**. Crates like [tokio](https://tokio.rs/) provide production-grade ones, but a minimal executor only requires to:
+//!
+//! * poll future,
+//! * when pending, wait for waker to be called,
+//! * poll again until ready.
+//!
+//! For example, following executor busily polls future (that's bad as it consumes CPU for nothing, you'll do better):
+//!
+//! ```rust
+//! use std::future::Future;
+//! use std::sync::Arc;
+//! use std::task::{Context, Poll, Wake, Waker};
+//!
+//! struct NoopWaker;
+//! impl Wake for NoopWaker {
+//!     fn wake(self: Arc<Self>) {}
+//! }
+//!
+//! fn spin_on<F: Future>(future: F) -> F::Output {
+//!     let mut future = Box::pin(future);
+//!     let waker = Waker::from(Arc::new(NoopWaker));
+//!     let mut cx = Context::from_waker(&waker);
+//!     loop {
+//!         if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+//!             return output;
+//!         }
+//!     }
+//! }
+//!
+//! assert_eq!(42, spin_on(async { 40 + 2 }));
+//! ```
+//!
+//! Get more info: [Asynchronous Programming in Rust](https://rust-lang.github.io/async-book/).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,402 @@
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// Future which is pending once before completing.
+struct YieldNow {
+    yielded: bool,
+}
+
+fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Future completing once `duration` has elapsed.
+///
+/// _Note: a thread is spawned on first poll to wake task up when deadline is reached._
+///
+struct Timer {
+    deadline: Instant,
+    waiting: bool,
+}
+
+impl Timer {
+    fn after(duration: Duration) -> Self {
+        Timer { deadline: Instant::now() + duration, waiting: false }
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        //TODO: complete only once deadline is reached, and wake task up when it is
+        Poll::Ready(())
+    }
+}
+
+struct ThreadWaker(Thread);
+
+//TODO: implement Wake for ThreadWaker
+
+/// Runs `future` on current thread until it completes.
+fn block_on<F: Future>(future: F) -> F::Output {
+    //TODO: park thread while future is pending, and unpark it from waker, instead of polling continuously
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+mod block_on_should {
+    use super::*;
+
+    #[test]
+    fn return_42_from_async_block() {
+        assert_eq!(42, block_on(async { 42 }));
+    }
+
+    #[test]
+    fn await_async_functions() {
+        async fn double(value: u32) -> u32 {
+            yield_now().await;
+            value * 2
+        }
+
+        assert_eq!(42, block_on(async { double(21).await }));
+    }
+
+    #[test]
+    fn wait_for_timer() {
+        let start = Instant::now();
+        block_on(Timer::after(Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    /// Future pending `remaining` times, waking itself up each time. Returns how many times it was polled.
+    struct Countdown {
+        remaining: u32,
+        polls: u32,
+    }
+
+    impl Future for Countdown {
+        type Output = u32;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            self.polls += 1;
+            if self.remaining == 0 {
+                return Poll::Ready(self.polls);
+            }
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn poll_again_each_time_woken() {
+        assert_eq!(4, block_on(Countdown { remaining: 3, polls: 0 }));
+    }
+}
+
+struct TaskWaker {
+    id: usize,
+    scheduler: Mutex<Sender<usize>>,
+}
+
+//TODO: implement Wake for TaskWaker
+
+/// Single-threaded executor running spawned tasks until all of them complete.
+///
+/// _Note: woken tasks are polled in order, so execution is deterministic._
+///
+struct Executor {
+    tasks: Vec<Option<Pin<Box<dyn Future<Output=()>>>>>,
+    scheduler: Sender<usize>,
+    scheduled: Receiver<usize>,
+}
+
+impl Executor {
+    fn new() -> Self {
+        let (scheduler, scheduled) = mpsc::channel();
+        Executor { tasks: vec![], scheduler, scheduled }
+    }
+
+    fn spawn<F: Future<Output=()> + 'static>(&mut self, future: F) {
+        //TODO: store task and schedule it
+    }
+
+    fn run(&mut self) {
+        //TODO: poll scheduled tasks until all of them complete
+    }
+}
+
+mod executor_should {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Future completing once fired by hand. Clones share same state, which counts how many times it was polled.
+    #[derive(Clone)]
+    struct Signal(Rc<RefCell<SignalState>>);
+
+    struct SignalState {
+        fired: bool,
+        polls: u32,
+        waker: Option<Waker>,
+    }
+
+    impl Signal {
+        fn new() -> Self {
+            Signal(Rc::new(RefCell::new(SignalState { fired: false, polls: 0, waker: None })))
+        }
+
+        fn fire(&self) {
+            let waker = {
+                let mut state = self.0.borrow_mut();
+                state.fired = true;
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+
+        fn polls(&self) -> u32 {
+            self.0.borrow().polls
+        }
+    }
+
+    impl Future for Signal {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.0.borrow_mut();
+            state.polls += 1;
+            if state.fired {
+                Poll::Ready(())
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn run_spawned_task() {
+        let result = Rc::new(RefCell::new(0));
+        let mut executor = Executor::new();
+
+        let output = result.clone();
+        executor.spawn(async move { *output.borrow_mut() = 42; });
+        executor.run();
+
+        assert_eq!(42, *result.borrow());
+    }
+
+    #[test]
+    fn interleave_tasks_when_yielding() {
+        let trace = Rc::new(RefCell::new(vec![]));
+        let mut executor = Executor::new();
+        for name in &["a", "b"] {
+            let trace = trace.clone();
+            executor.spawn(async move {
+                trace.borrow_mut().push(format!("{}1", name));
+                yield_now().await;
+                trace.borrow_mut().push(format!("{}2", name));
+            });
+        }
+        executor.run();
+
+        assert_eq!(vec!["a1", "b1", "a2", "b2"], *trace.borrow());
+    }
+
+    #[test]
+    fn poll_task_again_only_when_woken() {
+        let signal = Signal::new();
+        let mut executor = Executor::new();
+
+        executor.spawn(signal.clone());
+        let trigger = signal.clone();
+        executor.spawn(async move {
+            for _ in 0..3 {
+                yield_now().await;
+            }
+            trigger.fire();
+        });
+        executor.run();
+
+        assert_eq!(2, signal.polls());
+    }
+
+    #[test]
+    fn resume_tasks_in_wake_up_order() {
+        let trace = Rc::new(RefCell::new(vec![]));
+        let (slow, fast) = (Signal::new(), Signal::new());
+        let mut executor = Executor::new();
+        for (name, signal) in [("slow", slow.clone()), ("fast", fast.clone())] {
+            let trace = trace.clone();
+            executor.spawn(async move {
+                signal.await;
+                trace.borrow_mut().push(name);
+            });
+        }
+        executor.spawn(async move {
+            fast.fire();
+            yield_now().await;
+            slow.fire();
+        });
+        executor.run();
+
+        assert_eq!(vec!["fast", "slow"], *trace.borrow());
+    }
+}
+
+/// # channel
+/// Async version of `std::sync::mpsc::channel` for single-threaded executor (see 12-parallelism).
+mod channel {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    struct Shared<T> {
+        queue: VecDeque<T>,
+        senders: usize,
+        waker: Option<Waker>,
+    }
+
+    pub struct Sender<T>(Rc<RefCell<Shared<T>>>);
+
+    pub struct Receiver<T>(Rc<RefCell<Shared<T>>>);
+
+    pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+        let shared = Rc::new(RefCell::new(Shared { queue: VecDeque::new(), senders: 1, waker: None }));
+        (Sender(shared.clone()), Receiver(shared))
+    }
+
+    impl<T> Sender<T> {
+        pub fn send(&self, value: T) {
+            //TODO: push value and wake receiver up
+        }
+    }
+
+    impl<T> Clone for Sender<T> {
+        fn clone(&self) -> Self {
+            self.0.borrow_mut().senders += 1;
+            Sender(self.0.clone())
+        }
+    }
+
+    //TODO: wake receiver up when last sender is dropped
+
+    impl<T> Receiver<T> {
+        /// Waits for next value, or returns `None` when all senders are dropped.
+        pub fn recv(&self) -> Recv<'_, T> {
+            Recv(self)
+        }
+    }
+
+    pub struct Recv<'a, T>(&'a Receiver<T>);
+
+    impl<'a, T> Future for Recv<'a, T> {
+        type Output = Option<T>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            //TODO: return next value, or register waker if none is available yet
+            Poll::Ready(None)
+        }
+    }
+
+    mod should {
+        use super::channel;
+        use super::super::{Executor, yield_now};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[test]
+        fn transfer_message_between_tasks() {
+            let received = Rc::new(RefCell::new(None));
+            let (sender, receiver) = channel();
+            let mut executor = Executor::new();
+
+            let output = received.clone();
+            executor.spawn(async move {
+                *output.borrow_mut() = receiver.recv().await;
+            });
+            executor.spawn(async move {
+                yield_now().await;
+                sender.send("hello");
+            });
+            executor.run();
+
+            assert_eq!(Some("hello"), *received.borrow());
+        }
+
+        #[test]
+        fn support_multiple_producers_by_cloning_sender() {
+            let received = Rc::new(RefCell::new(vec![]));
+            let (sender0, receiver) = channel();
+            let sender1 = sender0.clone();
+            let mut executor = Executor::new();
+
+            let output = received.clone();
+            executor.spawn(async move {
+                while let Some(message) = receiver.recv().await {
+                    output.borrow_mut().push(message);
+                }
+            });
+            executor.spawn(async move {
+                sender0.send(1);
+            });
+            executor.spawn(async move {
+                yield_now().await;
+                sender1.send(2);
+            });
+            executor.run();
+
+            assert_eq!(vec![1, 2], *received.borrow());
+        }
+
+        #[test]
+        fn return_none_when_all_senders_are_dropped() {
+            let (sender, receiver) = channel::<u8>();
+            drop(sender);
+
+            let mut executor = Executor::new();
+            let received = Rc::new(RefCell::new(Some(0)));
+            let output = received.clone();
+            executor.spawn(async move {
+                *output.borrow_mut() = receiver.recv().await;
+            });
+            executor.run();
+
+            assert_eq!(None, *received.borrow());
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 19 Unsafe
+//! ---------
+//!
+//! Welcome to nineteenth step of this Rust workshop.
+//!
+//! This step focuses on what compiler can't check for you: `unsafe` code and calling C functions.
+//!
+//! ## Superpowers
+//!
+//! Borrow checker is conservative: it rejects some programs that are correct. An `unsafe` block unlocks a few extra capabilities, for which **you** guarantee safety instead of compiler:
+//!
+//! * dereferencing a raw pointer,
+//! * calling an `unsafe` function (including foreign ones),
+//! * accessing a mutable `static`,
+//! * implementing an `unsafe` trait (like `Send` or `Sync`),
+//! * accessing fields of a `union`.
+//!
+//! Everything else is still checked: `unsafe` doesn't disable borrow checker on references.
+//!
+//! ## Raw pointers
+//!
+//! Raw pointers `*const T` and `*mut T` are like references, but without any guarantee: they may be null, dangling, unaligned or aliased. Creating them is safe, only dereferencing requires `unsafe`:
+//!
+//! ```rust
+//! let mut value = 42;
+//! let read: *const i32 = &value;
+//! let write: *mut i32 = &mut value;
+//!
+//! unsafe {
+//!     *write += 1;
+//!     println!("{}", *read);
+//! }
+//! ```
+//!
+//! `Box::into_raw` gives up ownership of heap allocated data and returns a raw pointer. Memory is then leaked until `Box::from_raw` takes ownership back:
+//!
+//! ```rust
+//! let pointer: *mut String = Box::into_raw(Box::new(String::from("leaked")));
+//! let boxed: Box<String> = unsafe { Box::from_raw(pointer) }; // Will be dropped
+//! ```
+//!
+//! ## Unsafe functions
+//!
+//! A function marked `unsafe` has a _contract_ that caller must fulfil, and that compiler can't check. By convention, it is documented into a `# Safety` section:
+//!
+//! ```rust
+//! /// Returns first element of `values` without bounds checking.
+//! ///
+//! /// # Safety
+//! ///
+//! /// `values` must not be empty.
+//! unsafe fn first_unchecked(values: &[i32]) -> i32 {
+//!     *values.get_unchecked(0)
+//! }
+//!
+//! let values = [1, 2, 3];
+//! let first = unsafe { first_unchecked(&values) }; // Ok: values isn't empty
+//! ```
+//!
+//! ## Safe abstraction
+//!
+//! Most `unsafe` code is wrapped into a **safe** API, whose signature enforces contract at compile time or whose body checks it at runtime. That's how `std` is built: `Vec`, `String`, `Rc`, ... are all implemented with `unsafe` code.
+//!
+//! ```rust
+//! fn first(values: &[i32]) -> Option<i32> {
+//!     if values.is_empty() {
+//!         None
+//!     } else {
+//!         Some(unsafe { *values.get_unchecked(0) }) // Ok: checked just before
+//!     }
+//! }
+//! ```
+//!
+//! A safe function must be sound **whatever** its caller does. If a misuse of a safe API can lead to undefined behaviour, wrapper is buggy.
+//!
+//! To link a raw pointer to a lifetime, use [`std::marker::PhantomData`](https://doc.rust-lang.org/std/marker/struct.PhantomData.html). It's a zero-sized field acting for compiler as if struct contained given type:
+//!
+//! ```rust
+//! use std::marker::PhantomData;
+//!
+//! struct Cursor<'a> {
+//!     pointer: *const u8,
+//!     data: PhantomData<&'a [u8]>, // Cursor can't outlive borrowed data
+//! }
+//! ```
+//!
+//! ## Foreign Function Interface
+//!
+//! Functions from other languages are declared into an `extern "C"` block. As compiler can't check them, calling them is `unsafe`. C strings are NUL-terminated, so they are converted with [`std::ffi::CString`](https://doc.rust-lang.org/std/ffi/struct.CString.html) (owned) and [`std::ffi::CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html) (borrowed). C types are available under [`std::os::raw`](https://doc.rust-lang.org/std/os/raw/index.html).
+//!
+//! ```rust
+//! use std::ffi::CString;
+//! use std::os::raw::c_int;
+//!
+//! extern "C" {
+//!     fn abs(value: c_int) -> c_int;
+//! }
+//!
+//! assert_eq!(42, unsafe { abs(-42) });
+//! let c_string = CString::new("hello").unwrap(); // c_string.as_ptr() can be given to C
+//! ```
+//!
+//! _Note: C standard library is already linked by `std` on most platforms._
+//!
+//! Rust functions can also be called from C, for example as callbacks, when declared `extern "C"`:
+//!
+//! ```rust
+//! use std::os::raw::c_int;
+//!
+//! extern "C" fn double(value: c_int) -> c_int {
+//!     value * 2
+//! }
+//!
+//! let callback: extern "C" fn(c_int) -> c_int = double;
+//! ```
+//!
+//! Get more info: [The Rustonomicon](https://doc.rust-lang.org/nomicon/). [Miri](https://github.com/rust-lang/miri) also helps detecting undefined behaviour into tests.
+
+#[cfg(test)]
+mod tests;
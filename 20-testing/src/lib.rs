@@ -0,0 +1,151 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 20 Testing
+//! ----------
+//!
+//! Welcome to twentieth step of this Rust workshop.
+//!
+//! This step focuses on writing tests. Until now, you made tests pass ; it's now your turn to write them !
+//!
+//! _Note: in this step, code to test is into `src/lib.rs`, and tests have to be written into `src/tests.rs`, `tests/` directory and `src/lib.rs` documentation._
+//!
+//! ## Unit tests
+//!
+//! A test is a function annotated with `#[test]`. It fails when it panics, generally through assertion macros:
+//!
+//! ```rust,ignore
+//! #[test]
+//! fn addition_should_be_commutative() {
+//!     assert!(1 + 2 == 2 + 1);
+//!     assert_eq!(3, 1 + 2, "custom message with {}", "parameters");
+//!     assert_ne!(4, 1 + 2);
+//! }
+//! ```
+//!
+//! _Note: examples of this section are marked `ignore`, as a `#[test]` function inside a documentation test is never run._
+//!
+//! Test expecting a panic is annotated with `#[should_panic]` (see `division_should` in [09-error_management](../09_error_management/index.html)). `expected` parameter checks panic message contains given text:
+//!
+//! ```rust,ignore
+//! #[test]
+//! #[should_panic(expected = "divide by zero")]
+//! fn division_should_panic_when_divising_by_0() {
+//!     let zero = 0;
+//!     let _ = 42 / zero;
+//! }
+//! ```
+//!
+//! A test can also return a `Result` and then fails when it returns `Err`, so `?` operator can be used. Finally, slow tests can be annotated with `#[ignore]` and only run with `cargo test -- --ignored`.
+//!
+//! By convention, unit tests are written into a `tests` module annotated with `#[cfg(test)]`, so they're only compiled by `cargo test`. As a child module, it can access private items. That's what every step of this workshop does with its `src/tests.rs` file.
+//!
+//! ## Integration tests
+//!
+//! Each file of `tests/` directory is compiled as a separate crate depending on your library. Thus, integration tests only access **public** API, exactly as users of your crate would:
+//!
+//! ```rust,ignore
+//! // tests/public_api.rs
+//! extern crate workshop_testing;
+//!
+//! use workshop_testing::is_leap_year;
+//!
+//! #[test]
+//! fn year_2000_should_be_leap() {
+//!     assert!(is_leap_year(2000));
+//! }
+//! ```
+//!
+//! ## Documentation tests
+//!
+//! Code blocks into documentation are compiled and run as tests by `cargo test`. That ensures examples stay up-to-date. Lines starting with `# ` are hidden from documentation but still compiled. Code blocks can be annotated with `should_panic`, `compile_fail`, `no_run` or `ignore`.
+//!
+//! ```rust
+//! /// Returns twice `value`.
+//! ///
+//! /// ```
+//! /// # use my_crate::double;
+//! /// assert_eq!(4, double(2));
+//! /// ```
+//! pub fn double(value: i32) -> i32 {
+//!     value * 2
+//! }
+//! ```
+//!
+//! That's how examples of this workshop are checked, except the ones marked `ignore` !
+//!
+//! ## Assertion helpers
+//!
+//! When many tests check the same thing, extract a helper function (see `compare_tuple` in [08-generics](../08_generics/index.html)). Annotate it with `#[track_caller]` so failures are reported at calling line instead of into helper:
+//!
+//! ```rust
+//! #[track_caller]
+//! fn assert_even(value: i32) {
+//!     assert!(value % 2 == 0, "{} should be even", value);
+//! }
+//!
+//! assert_even(42);
+//! ```
+//!
+//! ## Fixtures
+//!
+//! Tests generally need some context (data, files, ...) to be set up before and cleaned up after. In Rust, such _fixture_ is a struct created by a function, and cleaned up by implementing `Drop` (see [13-memory](../13_memory/index.html)). Clean up then happens even when test fails, as values are also dropped during a panic:
+//!
+//! ```rust
+//! struct Fixture {
+//!     values: Vec<i32>,
+//! }
+//!
+//! impl Fixture {
+//!     fn new() -> Self {
+//!         println!("set up");
+//!         Fixture { values: vec![1, 2, 3] }
+//!     }
+//! }
+//!
+//! impl Drop for Fixture {
+//!     fn drop(&mut self) {
+//!         println!("clean up");
+//!     }
+//! }
+//! ```
+//!
+//! ## Property testing
+//!
+//! Instead of checking few hand-picked examples, _property testing_ checks that a property holds for many generated inputs. For example, sorting must keep same length, whatever values are. Crates like [proptest](https://crates.io/crates/proptest) do it very well, but a basic version only requires a pseudo-random generator.
+//!
+//! _Note: generator must be seeded with a fixed value, so a failing input can be reproduced._
+//!
+//! ## Testing tests
+//!
+//! How to know if tests are good ? A technique, called _mutation testing_, introduces bugs into tested code and checks that tests catch them. In this step, your tests are written as `check_*` functions taking implementation to test as parameter. A grader calls them against correct implementation (they must pass) and against buggy ones (they must fail).
+
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Returns whether `year` is a leap year in Gregorian calendar: every 4 years, except centuries which are not divisible by 400.
+///
+/// TODO: add an example, checked as documentation test
+pub fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Returns `values` sorted in ascending order.
+///
+/// TODO: add an example, checked as documentation test
+pub fn sort(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort();
+    values
+}
+
+/// Returns number of lines of file at `path`. Last line may or may not end with a newline.
+///
+/// TODO: add an example, checked as documentation test (hint: use `no_run`)
+pub fn line_count(path: &Path) -> io::Result<usize> {
+    Ok(fs::read_to_string(path)?.lines().count())
+}
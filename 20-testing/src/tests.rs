@@ -0,0 +1,168 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Write `check_*` functions so that they pass with correct implementation and fail with each mutant (see `grader` module).
+//!
+
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use super::{is_leap_year, sort, line_count};
+
+mod mutants;
+
+#[path = "../../common/compiler.rs"]
+mod compiler;
+
+fn check_leap_year(leap_year: fn(u32) -> bool) {
+    //TODO: check known leap and non-leap years
+}
+
+/// Checks `values` are in ascending order.
+#[track_caller]
+fn assert_sorted(values: &[i32]) {
+    //TODO: check each value is lower or equal to next one
+}
+
+/// Checks `actual` contains same values as `expected`, whatever their order.
+#[track_caller]
+fn assert_permutation(expected: &[i32], actual: &[i32]) {
+    //TODO: check both slices contains same values, whatever their order
+}
+
+/// Very simple pseudo-random generator ([xorshift](https://en.wikipedia.org/wiki/Xorshift)).
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.next_u64() % max
+    }
+
+    fn vec_i32(&mut self, max_len: u64) -> Vec<i32> {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| self.below(200) as i32 - 100).collect()
+    }
+}
+
+/// Checks `property` holds for `cases` inputs built by `generate`. Failing input is reported into panic message.
+fn for_all<T, G, P>(cases: usize, mut generate: G, property: P) where
+    T: std::fmt::Debug + panic::RefUnwindSafe,
+    G: FnMut(&mut Rng) -> T,
+    P: Fn(&T) + panic::RefUnwindSafe {
+    //TODO: generate inputs from a fixed seed, and run property against each one (hint: use `panic::catch_unwind`)
+}
+
+fn check_sort(sort: fn(Vec<i32>) -> Vec<i32>) {
+    //TODO: use `for_all`, `assert_sorted` and `assert_permutation`
+}
+
+/// File created with given content, and removed when dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn with_content(name: &str, content: &str) -> Self {
+        let path = compiler::temp_path(&format!("workshop_20_testing_{}", name));
+        fs::write(&path, content).unwrap();
+        TempFile { path }
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+//TODO: remove file when fixture is dropped
+
+fn check_line_count(line_count: fn(&Path) -> io::Result<usize>) {
+    //TODO: check with files created by `TempFile` fixture, and with a missing file
+}
+
+mod temp_file_should {
+    use super::TempFile;
+
+    #[test]
+    fn create_file_with_content() {
+        let file = TempFile::with_content("create", "content");
+        assert_eq!("content", std::fs::read_to_string(file.path()).unwrap());
+    }
+
+    #[test]
+    fn remove_file_when_dropped() {
+        let file = TempFile::with_content("remove", "content");
+        let path = file.path().to_path_buf();
+        drop(file);
+        assert!(!path.exists());
+    }
+}
+
+mod for_all_should {
+    use super::for_all;
+
+    #[test]
+    fn pass_when_property_always_holds() {
+        for_all(100, |rng| rng.vec_i32(10), |values| assert!(values.len() <= 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "with input")]
+    fn report_input_when_property_fails() {
+        for_all(100, |rng| rng.vec_i32(10), |values| assert!(values.len() < 5));
+    }
+}
+
+mod grader {
+    use super::*;
+
+    /// Returns names of mutants which don't make `check` fail.
+    fn survivors<F: Copy + panic::RefUnwindSafe>(mutants: &[(&'static str, F)], check: fn(F)) -> Vec<&'static str> {
+        mutants.iter()
+            .filter(|&&(_, mutant)| panic::catch_unwind(|| check(mutant)).is_ok())
+            .map(|&(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn check_leap_year_should_pass_with_correct_implementation() {
+        check_leap_year(is_leap_year);
+    }
+
+    #[test]
+    fn check_leap_year_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::LEAP_YEAR, check_leap_year), "surviving mutants");
+    }
+
+    #[test]
+    fn check_sort_should_pass_with_correct_implementation() {
+        check_sort(sort);
+    }
+
+    #[test]
+    fn check_sort_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::SORT, check_sort), "surviving mutants");
+    }
+
+    #[test]
+    fn check_line_count_should_pass_with_correct_implementation() {
+        check_line_count(line_count);
+    }
+
+    #[test]
+    fn check_line_count_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::LINE_COUNT, check_line_count), "surviving mutants");
+    }
+}
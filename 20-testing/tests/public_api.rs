@@ -0,0 +1,7 @@
+//!
+//! INSTRUCTIONS: Write integration tests for `is_leap_year` here. Only public API of crate is available.
+//!
+
+extern crate workshop_testing;
+
+//TODO: import `is_leap_year` and write some tests
@@ -0,0 +1,115 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 21 Trait object
+//! ---------------
+//!
+//! Welcome to twenty-first step of this Rust workshop.
+//!
+//! This step focuses on trait objects and dynamic dispatch.
+//!
+//! ## Static vs dynamic dispatch
+//!
+//! Generics (see [08-generics](../08_generics/index.html)) are _monomorphized_: compiler generates a copy of function for each concrete type. Method to call is known at compile time, that's **static dispatch**.
+//!
+//! ```rust
+//! use std::fmt::Display;
+//!
+//! fn show<T: Display>(value: T) -> String {
+//!     format!("<{}>", value)
+//! }
+//!
+//! show(42);     // Calls show::<i32>
+//! show("rust"); // Calls show::<&str>
+//! ```
+//!
+//! But a `Vec<T>` can only store a single type `T`. To store values of different types implementing a same trait, use a **trait object** `dyn Trait`. Method to call is then looked up at runtime, that's **dynamic dispatch**:
+//!
+//! ```rust
+//! use std::fmt::Display;
+//!
+//! let values: Vec<Box<dyn Display>> = vec![Box::new(42), Box::new("rust"), Box::new(3.14)];
+//! let shown: Vec<String> = values.iter().map(|value| format!("<{}>", value)).collect();
+//! assert_eq!(vec!["<42>", "<rust>", "<3.14>"], shown);
+//! ```
+//!
+//! _Note: in [06-trait](../06_trait/index.html), `format(ident: &Ident)` already took a trait object. It's the old syntax, `dyn` keyword has been added since to make dynamic dispatch explicit._
+//!
+//! ## Fat pointers
+//!
+//! Size of `dyn Trait` isn't known at compile time (it's a _dynamically sized type_), so a trait object is always used behind a pointer: `&dyn Trait`, `&mut dyn Trait`, `Box<dyn Trait>`, `Rc<dyn Trait>`, ... Such pointer is twice as large as a regular one: it stores address of data, and address of a _vtable_ containing methods of concrete type.
+//!
+//! ```rust
+//! use std::fmt::Display;
+//! use std::mem::size_of;
+//!
+//! assert_eq!(2 * size_of::<&i32>(), size_of::<&dyn Display>());
+//! ```
+//!
+//! ## Object safety
+//!
+//! Not every trait can be turned into a trait object. As concrete type is forgotten, a trait is _object safe_ (or _dyn compatible_) only if its methods don't need it, in particular:
+//!
+//! * they don't return `Self`,
+//! * they don't have generic type parameters.
+//!
+//! ```compile_fail
+//! trait Duplicate {
+//!     fn duplicate(&self) -> Self;
+//! }
+//!
+//! fn duplicate_all(values: &[Box<dyn Duplicate>]) {} // Compilation error: `Duplicate` cannot be made into an object
+//! ```
+//!
+//! A method can be excluded from trait object with a `where Self: Sized` bound. It can then only be called on concrete types:
+//!
+//! ```rust
+//! trait Duplicate {
+//!     fn name(&self) -> String;
+//!     fn duplicate(&self) -> Self where Self: Sized;
+//! }
+//!
+//! fn names(values: &[Box<dyn Duplicate>]) -> Vec<String> {
+//!     values.iter().map(|value| value.name()).collect()
+//! }
+//! ```
+//!
+//! ## Auto traits
+//!
+//! A trait object only implements its trait (and supertraits). To send it to another thread (see [17-shared_state](../17_shared_state/index.html)), it must also be declared `Send`. Either into type, with `Box<dyn Trait + Send>`, or by making `Send` a supertrait:
+//!
+//! ```rust
+//! use std::thread;
+//!
+//! trait Job: Send {
+//!     fn run(&self) -> u32;
+//! }
+//!
+//! struct Answer;
+//! impl Job for Answer {
+//!     fn run(&self) -> u32 { 42 }
+//! }
+//!
+//! let job: Box<dyn Job> = Box::new(Answer);
+//! assert_eq!(42, thread::spawn(move || job.run()).join().unwrap());
+//! ```
+//!
+//! ## Downcasting
+//!
+//! Sometimes, concrete type has to be retrieved from a trait object. [`std::any::Any`](https://doc.rust-lang.org/std/any/trait.Any.html) is implemented by every `'static` type and allows to _downcast_ to a concrete type, returning `None` when type doesn't match:
+//!
+//! ```rust
+//! use std::any::Any;
+//!
+//! let values: Vec<Box<dyn Any>> = vec![Box::new(42), Box::new("rust")];
+//! assert_eq!(Some(&42), values[0].downcast_ref::<i32>());
+//! assert_eq!(None, values[1].downcast_ref::<i32>());
+//! ```
+//!
+//! _Note: a trait object of your own trait can't be converted to `dyn Any` directly. Common trick is to add a `fn as_any(&self) -> &dyn Any` method, implemented by returning `self`._
+//!
+//! Get more info: [rust-book/trait-objects](https://doc.rust-lang.org/book/ch18-02-trait-objects.html).
+
+#[cfg(test)]
+mod tests;
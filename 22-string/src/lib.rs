@@ -0,0 +1,91 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 22 String
+//! ---------
+//!
+//! Welcome to twenty-second step of this Rust workshop.
+//!
+//! This step focuses on text processing, and on what `String` and `&str` really contain.
+//!
+//! ## UTF-8
+//!
+//! As seen in [01-basic](../01_basic/index.html), `String` is an owned and growable text, and `&str` is a borrowed slice of text. Both are **always** valid UTF-8: a character is encoded into 1 to 4 bytes.
+//!
+//! ```rust
+//! let text = "été 🦀";
+//! assert_eq!(10, text.len());           // Number of bytes
+//! assert_eq!(5, text.chars().count());  // Number of characters
+//! assert_eq!(&[0xC3, 0xA9], &text.as_bytes()[..2]); // 'é' is encoded into 2 bytes
+//! ```
+//!
+//! So `len()` is cheap, but counting characters requires to go through whole text.
+//!
+//! ## Bytes vs chars
+//!
+//! A `char` is a _Unicode scalar value_, always 4 bytes long. Text can be iterated by bytes, by chars, or by chars along with their byte index:
+//!
+//! ```rust
+//! let text = "aé";
+//! assert_eq!(vec![0x61, 0xC3, 0xA9], text.bytes().collect::<Vec<u8>>());
+//! assert_eq!(vec!['a', 'é'], text.chars().collect::<Vec<char>>());
+//! assert_eq!(vec![(0, 'a'), (1, 'é')], text.char_indices().collect::<Vec<(usize, char)>>());
+//! ```
+//!
+//! `char` provides many methods: `is_alphanumeric()`, `is_whitespace()`, `is_uppercase()`, `to_digit(10)`, ... Beware `to_uppercase()` and `to_lowercase()` return an iterator, as some characters are converted into many ones:
+//!
+//! ```rust
+//! assert_eq!("SS", 'ß'.to_uppercase().collect::<String>());
+//! assert_eq!("STRASSE", "straße".to_uppercase());
+//! ```
+//!
+//! _Note: what a user sees as a single character (a _grapheme_) may even be made of many `char`s, like `"é"` written as `'e'` followed by combining accent `'\u{301}'`. Handling graphemes requires an external crate like [unicode-segmentation](https://crates.io/crates/unicode-segmentation)._
+//!
+//! ## Slicing
+//!
+//! Indexing a string with a range uses **byte** indices. Slicing in the middle of a character panics at runtime:
+//!
+//! ```rust,should_panic
+//! let text = "été";
+//! assert_eq!("é", &text[0..2]);
+//! let broken = &text[0..1]; // Panics: byte index 1 is not a char boundary
+//! ```
+//!
+//! `is_char_boundary(index)` tells whether slicing at `index` is safe, and `get(range)` returns `None` instead of panicking. Single index (`text[0]`) isn't supported at all, as it is ambiguous.
+//!
+//! ## Searching and splitting
+//!
+//! Many methods accept a _pattern_: a `char`, a `&str`, or a closure `Fn(char) -> bool`.
+//!
+//! ```rust
+//! let line = "  key = some = value  ";
+//! assert_eq!("key = some = value", line.trim());
+//! assert_eq!(Some(2), line.find('k'));
+//! assert!(line.contains("some"));
+//!
+//! let parts: Vec<&str> = line.splitn(2, '=').map(str::trim).collect();
+//! assert_eq!(vec!["key", "some = value"], parts);
+//!
+//! let words: Vec<&str> = "one, two;three".split(|c: char| !c.is_alphanumeric()).collect();
+//! assert_eq!(vec!["one", "", "two", "three"], words);
+//! ```
+//!
+//! These methods return slices borrowing original text (see [05-lifetime](../05_lifetime/index.html)): no copy is made until you explicitly call `to_string()` or `to_owned()`.
+//!
+//! ## Building strings
+//!
+//! A `String` can be built with `push(char)`, `push_str(&str)`, `format!`, or by collecting an iterator of `char` or `&str`. When final size is roughly known, `String::with_capacity` avoids reallocations:
+//!
+//! ```rust
+//! let mut text = String::with_capacity(16);
+//! text.push_str("rust");
+//! text.push('a');
+//! text.extend("cean".chars());
+//! assert_eq!("rustacean", text);
+//! ```
+//!
+//! Get more info: [rust-book/strings](https://doc.rust-lang.org/book/ch08-02-strings.html).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,129 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 23 Parser
+//! ---------
+//!
+//! Welcome to twenty-third step of this Rust workshop.
+//!
+//! This step focuses on writing a parser for arithmetic expressions like `(1 + 2 * 3) * -(2 - 8)`. It puts together enums and pattern matching (see [07-pattern_matching](../07_pattern_matching/index.html)), recursive types with `Box` (see `Formula` in [13-memory](../13_memory/index.html)) and error management (see [09-error_management](../09_error_management/index.html)).
+//!
+//! ## Tokenizer
+//!
+//! First stage, called _tokenizer_ or _lexer_, splits text into meaningful units: numbers, operators, parentheses, ... Whitespaces are skipped. Each token keeps its position, so errors can tell where they happened.
+//!
+//! Tokens are read character by character. To read a multi-digit number, tokenizer has to look at next character without consuming it: that's what [`Peekable`](https://doc.rust-lang.org/std/iter/struct.Peekable.html) iterator adapter does.
+//!
+//! ```rust
+//! let mut chars = "42+".chars().peekable();
+//! let mut value = 0;
+//! while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+//!     value = value * 10 + digit;
+//!     chars.next();
+//! }
+//! assert_eq!(42, value);
+//! assert_eq!(Some('+'), chars.next());
+//! ```
+//!
+//! ## Abstract Syntax Tree
+//!
+//! Second stage, the _parser_, turns tokens into a tree: the _Abstract Syntax Tree_ (AST). Parentheses and precedence have disappeared, they are encoded into tree structure. For example `1 + 2 * 3` is:
+//!
+//! ```text
+//!   Add
+//!  /   \
+//! 1    Mul
+//!     /   \
+//!    2     3
+//! ```
+//!
+//! As seen with `Formula`, a recursive enum has to `Box` its children:
+//!
+//! ```rust
+//! enum Expr {
+//!     Number(i64),
+//!     Add(Box<Expr>, Box<Expr>),
+//!     Mul(Box<Expr>, Box<Expr>),
+//! }
+//!
+//! let expr = Expr::Add(Box::new(Expr::Number(1)), Box::new(Expr::Mul(Box::new(Expr::Number(2)), Box::new(Expr::Number(3)))));
+//! ```
+//!
+//! ## Grammar
+//!
+//! Syntax is described by a _grammar_, where each rule tells how it is made of tokens and of other rules. Precedence is obtained by having one rule per level, from lowest to highest priority:
+//!
+//! ```text
+//! expr   := term (('+' | '-') term)*
+//! term   := factor (('*' | '/') factor)*
+//! factor := number | '-' factor | '(' expr ')'
+//! ```
+//!
+//! ## Recursive descent
+//!
+//! A _recursive descent_ parser has one method per rule, calling each other as grammar does. Each method looks at current token to choose what to do. Loops build left-associative trees: `1 - 2 - 3` is `(1 - 2) - 3`.
+//!
+//! ```rust
+//! struct Parser {
+//!     tokens: Vec<char>,
+//!     index: usize,
+//! }
+//!
+//! impl Parser {
+//!     /// sum := digit ('+' digit)*
+//!     fn sum(&mut self) -> Result<u32, String> {
+//!         let mut total = self.digit()?;
+//!         while self.tokens.get(self.index) == Some(&'+') {
+//!             self.index += 1;
+//!             total += self.digit()?;
+//!         }
+//!         Ok(total)
+//!     }
+//!
+//!     /// digit := '0' | '1' | ... | '9'
+//!     fn digit(&mut self) -> Result<u32, String> {
+//!         match self.tokens.get(self.index).and_then(|c| c.to_digit(10)) {
+//!             Some(digit) => {
+//!                 self.index += 1;
+//!                 Ok(digit)
+//!             },
+//!             None => Err(format!("expected digit at position {}", self.index)),
+//!         }
+//!     }
+//! }
+//!
+//! let mut parser = Parser { tokens: "1+2+3".chars().collect(), index: 0 };
+//! assert_eq!(Ok(6), parser.sum());
+//! ```
+//!
+//! _Note: `?` operator stops parsing at first error. Don't forget to check all tokens have been consumed at the end!_
+//!
+//! ## Error reporting
+//!
+//! A good parser reports **what** went wrong and **where**. Implementing [`std::fmt::Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html) for error types gives users a readable message, while keeping a structured value for code:
+//!
+//! ```rust
+//! use std::fmt;
+//!
+//! struct ParseError {
+//!     position: usize,
+//!     message: String,
+//! }
+//!
+//! impl fmt::Display for ParseError {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         write!(f, "error at position {}: {}", self.position, self.message)
+//!     }
+//! }
+//!
+//! let error = ParseError { position: 4, message: String::from("unexpected character 'x'") };
+//! assert_eq!("error at position 4: unexpected character 'x'", error.to_string());
+//! ```
+//!
+//! Evaluation may also fail, for example on division by zero or overflow. `checked_add`, `checked_mul`, ... return `None` instead of panicking.
+//!
+//! Get more info: [Crafting Interpreters](https://craftinginterpreters.com/parsing-expressions.html).
+
+#[cfg(test)]
+mod tests;
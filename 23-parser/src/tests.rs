@@ -0,0 +1,346 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Token {
+    Number(i64),
+    Plus, Minus, Star, Slash,
+    LeftParen, RightParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Plus => write!(f, "'+'"),
+            Token::Minus => write!(f, "'-'"),
+            Token::Star => write!(f, "'*'"),
+            Token::Slash => write!(f, "'/'"),
+            Token::LeftParen => write!(f, "'('"),
+            Token::RightParen => write!(f, "')'"),
+        }
+    }
+}
+
+/// Error reported while reading input, at `position` (index of character, starting at 0).
+#[derive(Debug, PartialEq)]
+struct ParseError {
+    position: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(position: usize, message: String) -> Self {
+        ParseError { position, message }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error at position {}: {}", self.position, self.message)
+    }
+}
+
+/// Splits `input` into tokens, along with their position.
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '0'..='9' => Token::Number(c.to_digit(10).unwrap() as i64), //TODO: read following digits, and report too large number
+            c if c.is_whitespace() => continue,
+            c => return Err(ParseError::new(position, format!("unexpected character '{}'", c))),
+        };
+        tokens.push((position, token));
+    }
+    Ok(tokens)
+}
+
+mod tokenize_should {
+    use super::*;
+    use super::Token::*;
+
+    #[test]
+    fn return_no_token_when_blank() {
+        assert_eq!(Ok(vec![]), tokenize("  "));
+    }
+
+    #[test]
+    fn tokenize_operators_and_parentheses() {
+        assert_eq!(Ok(vec![(0, Plus), (1, Minus), (2, Star), (3, Slash), (4, LeftParen), (5, RightParen)]), tokenize("+-*/()"));
+    }
+
+    #[test]
+    fn tokenize_numbers() {
+        assert_eq!(Ok(vec![(0, Number(42)), (3, Number(0)), (5, Number(1337))]), tokenize("42 0 1337"));
+    }
+
+    #[test]
+    fn skip_whitespaces() {
+        assert_eq!(Ok(vec![(1, Number(1)), (3, Plus), (6, Number(2))]), tokenize(" 1 +\t\n2"));
+    }
+
+    #[test]
+    fn report_unexpected_character_with_its_position() {
+        let error = tokenize("1 + x").unwrap_err();
+        assert_eq!("error at position 4: unexpected character 'x'", error.to_string());
+    }
+
+    #[test]
+    fn report_position_in_characters() {
+        let error = tokenize("é?").unwrap_err();
+        assert_eq!("error at position 0: unexpected character 'é'", error.to_string());
+    }
+
+    #[test]
+    fn report_too_large_number() {
+        let error = tokenize("1 + 99999999999999999999").unwrap_err();
+        assert_eq!("error at position 4: number too large", error.to_string());
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Number(i64),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>), Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>), Div(Box<Expr>, Box<Expr>),
+}
+
+/// Recursive descent parser, following grammar:
+///
+/// ```text
+/// expr   := term (('+' | '-') term)*
+/// term   := factor (('*' | '/') factor)*
+/// factor := number | '-' factor | '(' expr ')'
+/// ```
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    /// Position reported when input is exhausted
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.index).map(|&(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.index).map_or(self.end, |&(position, _)| position)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(token) => ParseError::new(self.position(), format!("unexpected token {}", token)),
+            None => ParseError::new(self.position(), String::from("unexpected end of input")),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, ParseError> {
+        let mut left = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.index += 1;
+                    left = Expr::Add(Box::new(left), Box::new(self.term()?));
+                },
+                Some(Token::Minus) => {
+                    self.index += 1;
+                    left = Expr::Sub(Box::new(left), Box::new(self.term()?));
+                },
+                _ => return Ok(left),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        self.factor() //TODO: handle '*' and '/'
+    }
+
+    fn factor(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Some(Token::Number(value)) => {
+                self.index += 1;
+                Ok(Expr::Number(value))
+            },
+            //TODO: handle negation and parentheses
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+/// Parses whole `input` as an expression.
+fn parse(input: &str) -> Result<Expr, ParseError> {
+    let mut parser = Parser { tokens: tokenize(input)?, index: 0, end: input.chars().count() };
+    parser.expr() //TODO: report remaining tokens
+}
+
+mod parse_should {
+    use super::*;
+
+    fn num(value: i64) -> Box<Expr> {
+        Box::new(Expr::Number(value))
+    }
+
+    #[test]
+    fn parse_number() {
+        assert_eq!(Ok(Expr::Number(42)), parse("42"));
+    }
+
+    #[test]
+    fn parse_addition() {
+        assert_eq!(Ok(Expr::Add(num(1), num(2))), parse("1 + 2"));
+    }
+
+    #[test]
+    fn parse_multiplication_before_addition() {
+        assert_eq!(Ok(Expr::Add(num(1), Box::new(Expr::Mul(num(2), num(3))))), parse("1 + 2 * 3"));
+        assert_eq!(Ok(Expr::Sub(Box::new(Expr::Div(num(1), num(2))), num(3))), parse("1 / 2 - 3"));
+    }
+
+    #[test]
+    fn parse_left_associative_operators() {
+        assert_eq!(Ok(Expr::Sub(Box::new(Expr::Sub(num(1), num(2))), num(3))), parse("1 - 2 - 3"));
+        assert_eq!(Ok(Expr::Div(Box::new(Expr::Div(num(8), num(4))), num(2))), parse("8 / 4 / 2"));
+    }
+
+    #[test]
+    fn parse_parentheses() {
+        assert_eq!(Ok(Expr::Mul(Box::new(Expr::Add(num(1), num(2))), num(3))), parse("(1 + 2) * 3"));
+    }
+
+    #[test]
+    fn parse_negation() {
+        assert_eq!(Ok(Expr::Sub(num(1), Box::new(Expr::Neg(Box::new(Expr::Neg(num(2))))))), parse("1 - --2"));
+    }
+
+    #[test]
+    fn report_empty_input() {
+        assert_eq!("error at position 0: unexpected end of input", parse("").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn report_missing_operand() {
+        assert_eq!("error at position 7: unexpected end of input", parse("1 + 2 *").unwrap_err().to_string());
+        assert_eq!("error at position 4: unexpected token '*'", parse("1 + * 2").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn report_missing_operator() {
+        assert_eq!("error at position 2: unexpected token 2", parse("1 2").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn report_unclosed_parenthesis() {
+        assert_eq!("error at position 10: expected ')' to close '(' at position 4", parse("1 * (2 + 3").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn report_unexpected_closing_parenthesis() {
+        assert_eq!("error at position 5: unexpected token ')'", parse("1 + 2) * 3").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn report_tokenize_errors() {
+        assert_eq!("error at position 2: unexpected character '%'", parse("1 % 2").unwrap_err().to_string());
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum EvalError {
+    DivisionByZero,
+    Overflow,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::Overflow => write!(f, "overflow"),
+        }
+    }
+}
+
+impl Expr {
+    fn evaluate(&self) -> Result<i64, EvalError> {
+        match *self {
+            Expr::Number(value) => Ok(value),
+            Expr::Add(ref left, ref right) => Ok(left.evaluate()? + right.evaluate()?), //TODO: report overflow
+            _ => Ok(0), //TODO
+        }
+    }
+}
+
+mod evaluate_should {
+    use super::*;
+
+    fn evaluate(input: &str) -> Result<i64, EvalError> {
+        parse(input).unwrap().evaluate()
+    }
+
+    #[test]
+    fn evaluate_number() {
+        assert_eq!(Ok(42), evaluate("42"));
+    }
+
+    #[test]
+    fn evaluate_operators() {
+        assert_eq!(Ok(3), evaluate("1 + 2"));
+        assert_eq!(Ok(-1), evaluate("1 - 2"));
+        assert_eq!(Ok(6), evaluate("2 * 3"));
+        assert_eq!(Ok(3), evaluate("7 / 2"));
+        assert_eq!(Ok(-2), evaluate("-2"));
+    }
+
+    #[test]
+    fn evaluate_with_precedence() {
+        assert_eq!(Ok(7), evaluate("1 + 2 * 3"));
+        assert_eq!(Ok(9), evaluate("(1 + 2) * 3"));
+        assert_eq!(Ok(-4), evaluate("1 - 2 - 3"));
+        assert_eq!(Ok(1), evaluate("8 / 4 / 2"));
+    }
+
+    #[test]
+    fn report_division_by_zero() {
+        assert_eq!(Err(EvalError::DivisionByZero), evaluate("1 / (2 - 2)"));
+    }
+
+    #[test]
+    fn report_overflow() {
+        assert_eq!(Err(EvalError::Overflow), evaluate("9223372036854775807 + 1"));
+        assert_eq!(Err(EvalError::Overflow), evaluate("-9223372036854775807 - 2"));
+        assert_eq!(Err(EvalError::Overflow), evaluate("3037000500 * 3037000500"));
+    }
+}
+
+/// Parses and evaluates `input`, reporting any error as a message.
+fn calculate(input: &str) -> Result<i64, String> {
+    Ok(parse(input).unwrap().evaluate().unwrap()) //TODO: report errors as messages
+}
+
+mod calculate_should {
+    use super::*;
+
+    #[test]
+    fn calculate_expression() {
+        assert_eq!(Ok(42), calculate("(1 + 2 * 3) * -(2 - 8)"));
+    }
+
+    #[test]
+    fn report_parse_error() {
+        assert_eq!(Err(String::from("error at position 3: unexpected end of input")), calculate("1 +"));
+    }
+
+    #[test]
+    fn report_evaluation_error() {
+        assert_eq!(Err(String::from("cannot evaluate '1 / 0': division by zero")), calculate("1 / 0"));
+    }
+}
@@ -0,0 +1,103 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 24 I/O
+//! ------
+//!
+//! Welcome to twenty-fourth step of this Rust workshop.
+//!
+//! This step focuses on reading and writing files and streams with [`std::io`](https://doc.rust-lang.org/std/io/index.html) and [`std::fs`](https://doc.rust-lang.org/std/fs/index.html).
+//!
+//! ## Files
+//!
+//! Simplest way to read or write a whole file is `fs::read_to_string` and `fs::write`. For more control, [`std::fs::File`](https://doc.rust-lang.org/std/fs/struct.File.html) is opened with `File::open` (read-only), `File::create` (write-only, truncated) or [`OpenOptions`](https://doc.rust-lang.org/std/fs/struct.OpenOptions.html) (e.g. to append):
+//!
+//! ```rust,no_run
+//! use std::fs::{self, File, OpenOptions};
+//! use std::io::{self, Write};
+//!
+//! fn log(message: &str) -> io::Result<()> {
+//!     let mut file = OpenOptions::new().append(true).create(true).open("app.log")?;
+//!     writeln!(file, "{}", message)
+//! }
+//!
+//! fn main() -> io::Result<()> {
+//!     fs::write("hello.txt", "Hello world !")?;
+//!     let content = fs::read_to_string("hello.txt")?;
+//!     let file = File::open("missing.txt"); // Err(..) with kind ErrorKind::NotFound
+//!     log(&content)
+//! }
+//! ```
+//!
+//! All I/O operations return an [`io::Result<T>`](https://doc.rust-lang.org/std/io/type.Result.html), which is a `Result<T, io::Error>`. Error [`kind()`](https://doc.rust-lang.org/std/io/struct.Error.html#method.kind) tells what went wrong. Files are closed when dropped.
+//!
+//! ## Read & Write
+//!
+//! [`Read`](https://doc.rust-lang.org/std/io/trait.Read.html) and [`Write`](https://doc.rust-lang.org/std/io/trait.Write.html) traits abstract over source and destination of bytes: files, network sockets, standard input/output, or memory. `&[u8]` implements `Read` and `Vec<u8>` implements `Write`, which is very handy for tests:
+//!
+//! ```rust
+//! use std::io::{Read, Write};
+//!
+//! let mut input: &[u8] = b"hello";
+//! let mut content = String::new();
+//! input.read_to_string(&mut content).unwrap();
+//!
+//! let mut output: Vec<u8> = Vec::new();
+//! write!(output, "{} world", content).unwrap();
+//! assert_eq!(b"hello world".to_vec(), output);
+//! ```
+//!
+//! Only a single method has to be implemented: `read(&mut self, buf: &mut [u8])` fills beginning of `buf` and returns how many bytes were read (`0` means end of stream), and `write(&mut self, buf: &[u8])` returns how many bytes were written. Both may handle **less** bytes than asked, that's why `read_exact`, `read_to_end` or `write_all` loop until done. Other methods are provided on top of them.
+//!
+//! Implementing these traits for a type wrapping another reader or writer allows to build processing chains, used with any other I/O code like [`io::copy`](https://doc.rust-lang.org/std/io/fn.copy.html):
+//!
+//! ```rust
+//! use std::io::{self, Read};
+//!
+//! /// Reader replacing '-' by '_'
+//! struct Underscore<R: Read>(R);
+//!
+//! impl<R: Read> Read for Underscore<R> {
+//!     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+//!         let read = self.0.read(buf)?;
+//!         for byte in &mut buf[..read] {
+//!             if *byte == b'-' {
+//!                 *byte = b'_';
+//!             }
+//!         }
+//!         Ok(read)
+//!     }
+//! }
+//!
+//! let mut output = Vec::new();
+//! io::copy(&mut Underscore("snake-case".as_bytes()), &mut output).unwrap();
+//! assert_eq!(b"snake_case".to_vec(), output);
+//! ```
+//!
+//! ## Buffering
+//!
+//! Each call to `read` or `write` on a `File` is a system call, which is slow. [`BufReader`](https://doc.rust-lang.org/std/io/struct.BufReader.html) and [`BufWriter`](https://doc.rust-lang.org/std/io/struct.BufWriter.html) read and write by large chunks into memory.
+//!
+//! `BufReader` also implements [`BufRead`](https://doc.rust-lang.org/std/io/trait.BufRead.html), which allows to _stream_ lines: file is never entirely loaded into memory.
+//!
+//! ```rust
+//! use std::io::{BufRead, BufReader};
+//!
+//! let reader = BufReader::new("first\nsecond\n".as_bytes()); // Would be a File, generally
+//! for line in reader.lines() {
+//!     let line = line.unwrap(); // Each line may fail (I/O error, invalid UTF-8, ...)
+//!     println!("{}", line);
+//! }
+//! ```
+//!
+//! _Note: `BufWriter` writes remaining data when dropped, but then ignores errors. Call `flush()` to be notified of them._
+//!
+//! ## Custom errors
+//!
+//! As shown in [09-error_management](../09_error_management/index.html), an application generally defines its own error enum, wrapping errors of underlying layers. Implementing `From` for each of them allows `?` operator to convert them automatically.
+//!
+//! Get more info: [rust-book/reading-a-file](https://doc.rust-lang.org/book/ch12-02-reading-a-file.html).
+
+#[cfg(test)]
+mod tests;
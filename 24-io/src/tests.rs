@@ -0,0 +1,301 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+
+#[path = "../../common/compiler.rs"]
+mod compiler;
+
+/// Path of `name` in system temporary directory, unique to each call.
+fn temp_path(name: &str) -> PathBuf {
+    compiler::temp_path(&format!("workshop_24_io_{}", name))
+}
+
+/// Writes each of `lines` into file at `path`, followed by a newline. File is created or truncated.
+fn write_lines(path: &Path, lines: &[&str]) -> io::Result<()> {
+    Ok(()) //TODO
+}
+
+/// Appends `line` at the end of file at `path`, followed by a newline. File is created if missing.
+fn append_line(path: &Path, line: &str) -> io::Result<()> {
+    Ok(()) //TODO
+}
+
+/// Reads all lines of file at `path`.
+fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    Ok(vec![]) //TODO
+}
+
+/// Returns lines of `reader` containing `pattern`, along with their number (starting at 1).
+fn grep<R: BufRead>(reader: R, pattern: &str) -> io::Result<Vec<(usize, String)>> {
+    Ok(vec![]) //TODO
+}
+
+mod file_should {
+    use super::*;
+
+    #[test]
+    fn read_written_lines() {
+        let path = temp_path("lines.txt");
+        write_lines(&path, &["first", "", "third"]).unwrap();
+        assert_eq!("first\n\nthird\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(vec!["first", "", "third"], read_lines(&path).unwrap());
+    }
+
+    #[test]
+    fn truncate_when_writing_existing_file() {
+        let path = temp_path("lines.txt");
+        write_lines(&path, &["a", "b", "c"]).unwrap();
+        write_lines(&path, &["d"]).unwrap();
+        assert_eq!(vec!["d"], read_lines(&path).unwrap());
+    }
+
+    #[test]
+    fn append_lines() {
+        let path = temp_path("log.txt");
+        append_line(&path, "created").unwrap();
+        append_line(&path, "appended").unwrap();
+        assert_eq!(vec!["created", "appended"], read_lines(&path).unwrap());
+    }
+
+    #[test]
+    fn return_not_found_error_when_missing_file() {
+        let error = read_lines(&temp_path("missing.txt")).unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, error.kind());
+    }
+
+    #[test]
+    fn return_error_when_writing_into_missing_directory() {
+        assert!(write_lines(&temp_path("missing/lines.txt"), &["line"]).is_err());
+    }
+}
+
+mod grep_should {
+    use super::*;
+
+    #[test]
+    fn grep_in_memory_bytes() {
+        let input: &[u8] = b"rust\ncargo\ntrust\n";
+        assert_eq!(vec![(1, String::from("rust")), (3, String::from("trust"))], grep(input, "rust").unwrap());
+    }
+
+    #[test]
+    fn grep_in_file() {
+        let path = temp_path("grep.txt");
+        write_lines(&path, &["one", "two", "three"]).unwrap();
+        let reader = BufReader::new(File::open(&path).unwrap());
+        assert_eq!(vec![(2, String::from("two")), (3, String::from("three"))], grep(reader, "t").unwrap());
+    }
+
+    #[test]
+    fn return_error_when_invalid_utf8() {
+        let input: &[u8] = b"rust\n\xFF\xFE\n";
+        assert_eq!(io::ErrorKind::InvalidData, grep(input, "rust").unwrap_err().kind());
+    }
+}
+
+/// Writer counting bytes written into `inner`.
+struct CountingWriter<W: Write> {
+    inner: W,
+    count: usize,
+}
+
+impl<W: Write> CountingWriter<W> {
+    fn new(inner: W) -> Self {
+        CountingWriter { inner, count: 0 }
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf) //TODO: count written bytes
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writer accepting only `capacity` bytes at each call.
+struct SlowWriter {
+    written: Vec<u8>,
+    capacity: usize,
+}
+
+impl Write for SlowWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.capacity);
+        self.written.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+mod counting_writer_should {
+    use super::*;
+
+    #[test]
+    fn count_nothing_when_nothing_written() {
+        assert_eq!(0, CountingWriter::new(Vec::new()).count());
+    }
+
+    #[test]
+    fn count_written_bytes() {
+        let mut writer = CountingWriter::new(Vec::new());
+        write!(writer, "{} {}", "été", 42).unwrap();
+        writer.write_all(b"!").unwrap();
+        assert_eq!(9, writer.count());
+        assert_eq!(b"\xC3\xA9t\xC3\xA9 42!".to_vec(), writer.into_inner());
+    }
+
+    #[test]
+    fn count_only_bytes_accepted_by_inner_writer() {
+        let mut writer = CountingWriter::new(SlowWriter { written: vec![], capacity: 2 });
+        assert_eq!(2, writer.write(b"rust").unwrap());
+        assert_eq!(2, writer.count());
+        writer.write_all(b"acean").unwrap();
+        assert_eq!(7, writer.count());
+        assert_eq!(b"ruacean".to_vec(), writer.into_inner().written);
+    }
+}
+
+/// Reader applying ROT13 (letters rotated by 13 places) to content of `inner`.
+struct Rot13Reader<R: Read> {
+    inner: R,
+}
+
+impl<R: Read> Rot13Reader<R> {
+    fn new(inner: R) -> Self {
+        Rot13Reader { inner }
+    }
+}
+
+/// Rotates ASCII letters by 13 places. Other bytes (including UTF-8 ones) are kept.
+fn rot13(byte: u8) -> u8 {
+    byte //TODO
+}
+
+impl<R: Read> Read for Rot13Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf) //TODO: apply rot13
+    }
+}
+
+mod rot13_reader_should {
+    use super::*;
+
+    fn decode(input: &str) -> String {
+        let mut output = String::new();
+        Rot13Reader::new(input.as_bytes()).read_to_string(&mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn rotate_letters() {
+        assert_eq!("nopqrstuvwxyzabcdefghijklm", decode("abcdefghijklmnopqrstuvwxyz"));
+        assert_eq!("NOPQRSTUVWXYZABCDEFGHIJKLM", decode("ABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+    }
+
+    #[test]
+    fn keep_other_characters() {
+        assert_eq!("Uryyb, jbeyq ! 42 éèê", decode("Hello, world ! 42 éèê"));
+    }
+
+    #[test]
+    fn be_reversible() {
+        assert_eq!("Rustacean", decode(&decode("Rustacean")));
+    }
+
+    #[test]
+    fn be_chained_with_other_readers_and_writers() {
+        let path = temp_path("secret.txt");
+        write_lines(&path, &["Ehfg vf sha"]).unwrap();
+
+        let mut reader = BufReader::new(Rot13Reader::new(File::open(&path).unwrap()));
+        let mut writer = CountingWriter::new(Vec::new());
+        io::copy(&mut reader, &mut writer).unwrap();
+        assert_eq!(12, writer.count());
+        assert_eq!(b"Rust is fun\n".to_vec(), writer.into_inner());
+    }
+}
+
+#[derive(Debug)]
+enum ConfigError {
+    Io(io::Error),
+    Syntax { line: usize },
+    Number(ParseIntError),
+}
+
+//TODO: convert `io::Error` and `ParseIntError` into `ConfigError`
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::Io(ref error) => write!(f, "cannot read configuration: {}", error),
+            ConfigError::Syntax { line } => write!(f, "missing ':' at line {}", line),
+            ConfigError::Number(ref error) => write!(f, "invalid number: {}", error),
+        }
+    }
+}
+
+/// Loads `key: number` lines of file at `path`.
+fn load_config(path: &Path) -> Result<HashMap<String, u32>, ConfigError> {
+    Ok(HashMap::new()) //TODO: use `?` operator
+}
+
+mod load_config_should {
+    use super::*;
+
+    fn load(lines: &[&str]) -> Result<HashMap<String, u32>, ConfigError> {
+        let path = temp_path("config.txt");
+        write_lines(&path, lines).unwrap();
+        load_config(&path)
+    }
+
+    #[test]
+    fn load_key_values() {
+        let config = load(&["threads: 4", "timeout:30"]).unwrap();
+        assert_eq!(2, config.len());
+        assert_eq!(Some(&4), config.get("threads"));
+        assert_eq!(Some(&30), config.get("timeout"));
+    }
+
+    #[test]
+    fn report_io_error() {
+        match load_config(&temp_path("missing.txt")) {
+            Err(ConfigError::Io(ref error)) => assert_eq!(io::ErrorKind::NotFound, error.kind()),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn report_syntax_error() {
+        let error = load(&["threads: 4", "timeout 30"]).unwrap_err();
+        assert_eq!("missing ':' at line 2", error.to_string());
+    }
+
+    #[test]
+    fn report_number_error() {
+        let error = load(&["threads: four"]).unwrap_err();
+        assert_eq!("invalid number: invalid digit found in string", error.to_string());
+        match error {
+            ConfigError::Number(_) => {},
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
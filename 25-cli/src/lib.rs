@@ -0,0 +1,149 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 25 CLI
+//! ------
+//!
+//! Welcome to twenty-fifth step of this Rust workshop.
+//!
+//! This step focuses on writing a command-line tool with standard library only: `count`, a tiny clone of Unix `wc`.
+//!
+//! _Note: this crate contains both a library (`src/lib.rs`) and a binary (`src/main.rs`), see `Cargo.toml`. Run it with `cargo run --bin count -- --help`._
+//!
+//! ## Binary crate
+//!
+//! A binary is a crate with a `main` function, like the one at workshop root. Cargo builds `src/main.rs` automatically ; other binaries are declared with `[[bin]]` sections into `Cargo.toml`.
+//!
+//! Good practice is to keep `main` as small as possible and put logic into library, which is easier to test. Binary then uses library as any other crate:
+//!
+//! ```rust,ignore
+//! extern crate workshop_cli;
+//!
+//! fn main() {
+//!     workshop_cli::do_something();
+//! }
+//! ```
+//!
+//! ## Arguments
+//!
+//! [`std::env::args()`](https://doc.rust-lang.org/std/env/fn.args.html) returns an iterator over command-line arguments. First one is program name, generally skipped:
+//!
+//! ```rust
+//! use std::env;
+//!
+//! let args: Vec<String> = env::args().skip(1).collect();
+//! ```
+//!
+//! Rather than looking at raw strings everywhere, parse them once into a typed configuration, generally an enum (see [07-pattern_matching](../07_pattern_matching/index.html)). Taking any `IntoIterator<Item = String>` instead of calling `env::args()` directly makes parsing easy to test:
+//!
+//! ```rust
+//! #[derive(Debug, PartialEq)]
+//! enum Config {
+//!     Help,
+//!     Greet { name: String },
+//! }
+//!
+//! fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Config, String> {
+//!     let mut name = None;
+//!     for arg in args {
+//!         match arg.as_str() {
+//!             "-h" | "--help" => return Ok(Config::Help),
+//!             option if option.starts_with('-') => return Err(format!("unknown option '{}'", option)),
+//!             _ => name = Some(arg),
+//!         }
+//!     }
+//!     name.map(|name| Config::Greet { name }).ok_or(String::from("missing name"))
+//! }
+//!
+//! assert_eq!(Ok(Config::Help), parse_args(vec![String::from("--help")]));
+//! ```
+//!
+//! _Note: real-world tools generally use [clap](https://crates.io/crates/clap) crate, which also generates help._
+//!
+//! ## Standard streams
+//!
+//! A command-line tool reads [`std::io::stdin()`](https://doc.rust-lang.org/std/io/fn.stdin.html) and writes results to [`stdout()`](https://doc.rust-lang.org/std/io/fn.stdout.html), so it can be chained with other tools (`cat notes.txt | count -l`). Errors are written to [`stderr()`](https://doc.rust-lang.org/std/io/fn.stderr.html), so they don't mix with results. As for any other I/O (see [24-io](../24_io/index.html)), code should be generic over `BufRead`/`Write`:
+//!
+//! ```rust,no_run
+//! use std::io::{self, BufRead, Write};
+//!
+//! fn shout<R: BufRead, W: Write>(input: R, output: &mut W) -> io::Result<()> {
+//!     for line in input.lines() {
+//!         writeln!(output, "{}", line?.to_uppercase())?;
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let stdin = io::stdin();
+//! shout(stdin.lock(), &mut io::stdout()).unwrap(); // Locking stdin gives a BufRead
+//! ```
+//!
+//! ## Exit code
+//!
+//! A process returns an _exit code_ to its caller: `0` means success, anything else means failure. By convention, `2` is used for wrong usage (unknown option, missing argument, ...). [`std::process::exit(code)`](https://doc.rust-lang.org/std/process/fn.exit.html) stops process immediately with given code.
+//!
+//! _Note: `exit` doesn't run destructors. Make sure everything has been flushed before calling it._
+//!
+//! ## Testing binary
+//!
+//! Integration tests (see [20-testing](../20_testing/index.html)) can also run compiled binary as a subprocess, with [`std::process::Command`](https://doc.rust-lang.org/std/process/struct.Command.html). Cargo builds binaries before running them, and gives their path into `CARGO_BIN_EXE_<name>` environment variable at compile time:
+//!
+//! ```rust,ignore
+//! use std::process::Command;
+//!
+//! let output = Command::new(env!("CARGO_BIN_EXE_count")).arg("--help").output().unwrap();
+//! assert!(output.status.success());
+//! assert!(String::from_utf8(output.stdout).unwrap().starts_with("Usage"));
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+
+pub const USAGE: &str = "\
+Usage: count [OPTIONS] [FILE]
+
+Counts lines, words and bytes of FILE, or of standard input when FILE is missing.
+
+Options:
+  -l, --lines    Print line count
+  -w, --words    Print word count
+  -c, --bytes    Print byte count
+  -h, --help     Print this help
+";
+
+#[derive(Debug, PartialEq)]
+pub enum Config {
+    Help,
+    Count { lines: bool, words: bool, bytes: bool, file: Option<String> },
+}
+
+/// Parses command-line arguments (without program name). When no count is selected, all of them are printed.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Config, String> {
+    Ok(Config::Help) //TODO
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub bytes: usize,
+}
+
+/// Counts newlines, whitespace-separated words and bytes of `reader`, line by line.
+pub fn count<R: BufRead>(reader: R) -> io::Result<Counts> {
+    Ok(Counts::default()) //TODO
+}
+
+fn execute<R: BufRead, W: Write>(config: Config, stdin: R, stdout: &mut W) -> Result<(), String> {
+    Ok(()) //TODO: print help, or selected counts of file (or stdin when missing)
+}
+
+/// Runs `count` program and returns its exit code: `0` on success, `1` on runtime error, `2` on usage error.
+pub fn run<I, R, W, E>(args: I, stdin: R, stdout: &mut W, stderr: &mut E) -> i32 where
+    I: IntoIterator<Item = String>, R: BufRead, W: Write, E: Write {
+    0 //TODO: parse `args` and execute config, reporting errors into `stderr`
+}
@@ -0,0 +1,6 @@
+extern crate workshop_cli;
+
+fn main() {
+    //TODO: run `workshop_cli::run` with command-line arguments (without program name), standard input, output and error
+    //TODO: exit with returned code
+}
@@ -0,0 +1,88 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 26 Network
+//! ----------
+//!
+//! Welcome to twenty-sixth step of this Rust workshop.
+//!
+//! This step focuses on writing TCP servers with [`std::net`](https://doc.rust-lang.org/std/net/index.html): a line-based echo server, then a key-value store.
+//!
+//! ## TCP
+//!
+//! TCP provides a reliable and ordered stream of bytes between two programs. A server _listens_ on an address (IP and port), and clients _connect_ to it. Each accepted connection is a [`TcpStream`](https://doc.rust-lang.org/std/net/struct.TcpStream.html), which implements `Read` and `Write` (see [24-io](../24_io/index.html)):
+//!
+//! ```rust
+//! use std::io::{Read, Write};
+//! use std::net::{TcpListener, TcpStream};
+//!
+//! let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+//! let addr = listener.local_addr().unwrap();
+//!
+//! let mut client = TcpStream::connect(addr).unwrap();
+//! let (mut server, _) = listener.accept().unwrap();
+//!
+//! client.write_all(b"ping").unwrap();
+//! let mut buffer = [0; 4];
+//! server.read_exact(&mut buffer).unwrap();
+//! assert_eq!(b"ping", &buffer);
+//! ```
+//!
+//! Binding to port `0` lets operating system choose a free port: that's how tests can run in parallel without conflict. `127.0.0.1` (_loopback_) is only reachable from local machine.
+//!
+//! ## Line-based protocol
+//!
+//! TCP has no notion of message: it's up to protocol to tell where a message ends. Simplest way is to send one message per line. As seen in [24-io](../24_io/index.html), `BufReader` reads lines, and `try_clone` gives a second handle to the same stream for writing:
+//!
+//! ```rust,no_run
+//! use std::io::{self, BufRead, BufReader, Write};
+//! use std::net::TcpStream;
+//!
+//! fn shout(stream: TcpStream) -> io::Result<()> {
+//!     let mut writer = stream.try_clone()?;
+//!     for line in BufReader::new(stream).lines() {
+//!         writeln!(writer, "{}", line?.to_uppercase())?;
+//!     }
+//!     Ok(()) // Client has disconnected
+//! }
+//! ```
+//!
+//! Parsing requests is pattern matching again (see [07-pattern_matching](../07_pattern_matching/index.html)). _Slice patterns_ match on length and content of a slice at the same time:
+//!
+//! ```rust
+//! fn describe(words: &[&str]) -> String {
+//!     match words {
+//!         [] => String::from("nothing"),
+//!         ["hello", name] => format!("greetings to {}", name),
+//!         [first, .., last] => format!("from {} to {}", first, last),
+//!         [single] => format!("only {}", single),
+//!     }
+//! }
+//!
+//! assert_eq!("greetings to Ferris", describe(&["hello", "Ferris"]));
+//! assert_eq!("from a to c", describe(&["a", "b", "c"]));
+//! ```
+//!
+//! ## Many clients
+//!
+//! `listener.incoming()` iterates over incoming connections, forever. Handling a connection blocks until client disconnects, so a server handling connections one after the other can only serve one client at a time. Simplest solution is to spawn a thread per connection (see [12-parallelism](../12_parallelism/index.html)). State shared between connections is then protected by `Arc<Mutex<..>>` (see [17-shared_state](../17_shared_state/index.html)).
+//!
+//! ## Thread pool
+//!
+//! Spawning a thread per connection doesn't scale: thousands of clients means thousands of threads. A _thread pool_ starts a fixed number of _worker_ threads once, and sends them jobs to run through a channel. As `mpsc::Receiver` can't be shared, workers take turns to receive from it thanks to a `Mutex`:
+//!
+//! ```text
+//!                                   ┌──> worker 1
+//! execute(job) ──> Sender ──> Arc<Mutex<Receiver>> ──> worker 2
+//!                                   └──> worker 3
+//! ```
+//!
+//! A job is a closure run once on another thread: `Box<dyn FnOnce() + Send + 'static>`. When pool is dropped, dropping sender makes workers' `recv()` fail, so they stop.
+//!
+//! _Note: for high-performance servers, asynchronous I/O (see [18-async](../18_async/index.html)) goes further by serving many connections per thread._
+//!
+//! Get more info: [rust-book/web-server](https://doc.rust-lang.org/book/ch21-00-final-project-a-web-server.html).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,318 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Binds an ephemeral port on loopback, chosen by operating system.
+fn bind() -> TcpListener {
+    TcpListener::bind("127.0.0.1:0").unwrap()
+}
+
+/// Connection to a line-based server.
+struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    fn connect(addr: SocketAddr) -> Self {
+        let stream = TcpStream::connect(addr).unwrap();
+        // Don't wait forever for a server which doesn't answer
+        stream.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        Client { reader: BufReader::new(stream.try_clone().unwrap()), writer: stream }
+    }
+
+    /// Sends `request` line and returns response line (without newline).
+    fn send(&mut self, request: &str) -> io::Result<String> {
+        writeln!(self.writer, "{}", request)?;
+        let mut response = String::new();
+        if self.reader.read_line(&mut response)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed"));
+        }
+        Ok(String::from(response.trim_end_matches('\n')))
+    }
+
+    fn set_read_timeout(&self, timeout: Duration) {
+        self.writer.set_read_timeout(Some(timeout)).unwrap();
+    }
+}
+
+/// Writes back each line received on `stream`, until client disconnects.
+fn handle_echo(stream: TcpStream) -> io::Result<()> {
+    Ok(()) //TODO
+}
+
+/// Accepts connections forever, handling each one into its own thread.
+fn serve_thread_per_connection<H>(listener: TcpListener, handler: H) where
+    H: Fn(TcpStream) -> io::Result<()> + Send + Clone + 'static {
+    for stream in listener.incoming() {
+        let _ = handler(stream.unwrap()); //TODO: handle each connection into its own thread
+    }
+}
+
+/// Starts serving on an ephemeral port into background, and returns its address.
+fn start<H>(handler: H) -> SocketAddr where
+    H: Fn(TcpStream) -> io::Result<()> + Send + Clone + 'static {
+    let listener = bind();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve_thread_per_connection(listener, handler));
+    addr
+}
+
+mod echo_server_should {
+    use super::*;
+
+    #[test]
+    fn echo_lines() {
+        let mut client = Client::connect(start(handle_echo));
+        assert_eq!("Hello", client.send("Hello").unwrap());
+        assert_eq!("world !", client.send("world !").unwrap());
+    }
+
+    #[test]
+    fn serve_many_clients_at_the_same_time() {
+        let addr = start(handle_echo);
+        let mut first = Client::connect(addr);
+        let mut second = Client::connect(addr);
+        assert_eq!("second", second.send("second").unwrap());
+        assert_eq!("first", first.send("first").unwrap());
+    }
+
+    #[test]
+    fn serve_many_clients_concurrently() {
+        let addr = start(handle_echo);
+        let handles: Vec<_> = (0..10).map(|index| thread::spawn(move || {
+            let mut client = Client::connect(addr);
+            (0..10).all(|round| {
+                let message = format!("client {} round {}", index, round);
+                client.send(&message).unwrap() == message
+            })
+        })).collect();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Get(String),
+    Set(String, String),
+    Delete(String),
+}
+
+/// Parses `GET key`, `SET key value` and `DEL key` requests. Value may contain spaces.
+fn parse_command(line: &str) -> Result<Command, String> {
+    Err(String::new()) //TODO: use slice patterns
+}
+
+mod parse_command_should {
+    use super::*;
+
+    #[test]
+    fn parse_get() {
+        assert_eq!(Ok(Command::Get(String::from("name"))), parse_command("GET name"));
+    }
+
+    #[test]
+    fn parse_set_with_spaces_into_value() {
+        assert_eq!(Ok(Command::Set(String::from("name"), String::from("Ferris the crab"))), parse_command("SET name Ferris the crab"));
+    }
+
+    #[test]
+    fn parse_delete() {
+        assert_eq!(Ok(Command::Delete(String::from("name"))), parse_command("DEL name"));
+    }
+
+    #[test]
+    fn reject_wrong_number_of_arguments() {
+        assert_eq!(Err(String::from("wrong number of arguments for GET")), parse_command("GET"));
+        assert_eq!(Err(String::from("wrong number of arguments for SET")), parse_command("SET name"));
+        assert_eq!(Err(String::from("wrong number of arguments for DEL")), parse_command("DEL a b"));
+    }
+
+    #[test]
+    fn reject_unknown_command() {
+        assert_eq!(Err(String::from("unknown command 'PUT'")), parse_command("PUT name value"));
+        assert_eq!(Err(String::from("unknown command ''")), parse_command(""));
+    }
+}
+
+type Store = Arc<Mutex<HashMap<String, String>>>;
+
+/// Executes `command` against `store` and returns response line: `OK`, `VALUE <value>` or `NOT_FOUND`.
+fn execute(command: Command, store: &Store) -> String {
+    String::from("NOT_FOUND") //TODO
+}
+
+/// Answers each request line received on `stream`. Invalid requests are answered with `ERROR <message>`.
+fn handle_kv(stream: TcpStream, store: Store) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let response = match parse_command(&line?) {
+            Ok(command) => execute(command, &store),
+            Err(message) => format!("ERROR {}", message),
+        };
+        writeln!(writer, "{}", response)?;
+    }
+    Ok(())
+}
+
+fn start_kv() -> SocketAddr {
+    let store = Store::default();
+    start(move |stream| handle_kv(stream, store.clone()))
+}
+
+mod kv_server_should {
+    use super::*;
+
+    #[test]
+    fn return_not_found_when_missing_key() {
+        let mut client = Client::connect(start_kv());
+        assert_eq!("NOT_FOUND", client.send("GET name").unwrap());
+        assert_eq!("NOT_FOUND", client.send("DEL name").unwrap());
+    }
+
+    #[test]
+    fn get_set_value() {
+        let mut client = Client::connect(start_kv());
+        assert_eq!("OK", client.send("SET name Ferris").unwrap());
+        assert_eq!("VALUE Ferris", client.send("GET name").unwrap());
+        assert_eq!("OK", client.send("SET name Ferris the crab").unwrap());
+        assert_eq!("VALUE Ferris the crab", client.send("GET name").unwrap());
+    }
+
+    #[test]
+    fn delete_value() {
+        let mut client = Client::connect(start_kv());
+        client.send("SET name Ferris").unwrap();
+        assert_eq!("OK", client.send("DEL name").unwrap());
+        assert_eq!("NOT_FOUND", client.send("GET name").unwrap());
+    }
+
+    #[test]
+    fn report_invalid_requests_and_keep_connection() {
+        let mut client = Client::connect(start_kv());
+        assert_eq!("ERROR unknown command 'PUT'", client.send("PUT name Ferris").unwrap());
+        assert_eq!("ERROR wrong number of arguments for GET", client.send("GET").unwrap());
+        assert_eq!("NOT_FOUND", client.send("GET name").unwrap());
+    }
+
+    #[test]
+    fn share_store_between_clients() {
+        let addr = start_kv();
+        let mut writer = Client::connect(addr);
+        let mut reader = Client::connect(addr);
+        writer.send("SET language Rust").unwrap();
+        assert_eq!("VALUE Rust", reader.send("GET language").unwrap());
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Runs jobs on a fixed number of threads.
+struct ThreadPool {
+    //TODO: send jobs to worker threads through a channel
+}
+
+impl ThreadPool {
+    fn new(size: usize) -> Self {
+        ThreadPool {} //TODO: start `size` worker threads
+    }
+
+    fn execute<F: FnOnce() + Send + 'static>(&self, job: F) {
+        job() //TODO: send job to a worker thread
+    }
+}
+
+impl Drop for ThreadPool {
+    /// Waits for all submitted jobs to complete.
+    fn drop(&mut self) {
+        //TODO: stop and join worker threads
+    }
+}
+
+mod thread_pool_should {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn run_all_jobs_before_being_dropped() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        {
+            let pool = ThreadPool::new(3);
+            for _ in 0..20 {
+                let counter = counter.clone();
+                pool.execute(move || {
+                    thread::sleep(Duration::from_millis(1));
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        }
+        assert_eq!(20, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn run_jobs_on_several_threads() {
+        let pool = ThreadPool::new(2);
+        let (ping, pong) = mpsc::channel();
+        let (sender, result) = mpsc::channel();
+        // First job can only complete when second one runs at the same time
+        pool.execute(move || sender.send(pong.recv_timeout(Duration::from_secs(2)).is_ok()).unwrap());
+        pool.execute(move || {
+            let _ = ping.send(());
+        });
+        assert_eq!(Ok(true), result.recv_timeout(Duration::from_secs(3)));
+    }
+}
+
+/// Accepts connections forever, handling them with at most `size` threads.
+fn serve_with_pool<H>(listener: TcpListener, size: usize, handler: H) where
+    H: Fn(TcpStream) -> io::Result<()> + Send + Clone + 'static {
+    for stream in listener.incoming() {
+        let _ = handler(stream.unwrap()); //TODO: handle connections with a thread pool of `size` threads
+    }
+}
+
+mod pool_server_should {
+    use super::*;
+
+    fn start_with_pool(size: usize) -> SocketAddr {
+        let listener = bind();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve_with_pool(listener, size, handle_echo));
+        addr
+    }
+
+    #[test]
+    fn serve_as_many_clients_as_threads_at_the_same_time() {
+        let addr = start_with_pool(2);
+        let mut first = Client::connect(addr);
+        let mut second = Client::connect(addr);
+        assert_eq!("second", second.send("second").unwrap());
+        assert_eq!("first", first.send("first").unwrap());
+    }
+
+    #[test]
+    fn make_extra_client_wait_until_a_thread_is_free() {
+        let addr = start_with_pool(2);
+        let mut first = Client::connect(addr);
+        let mut second = Client::connect(addr);
+        assert_eq!("first", first.send("first").unwrap());
+        assert_eq!("second", second.send("second").unwrap());
+
+        let mut third = Client::connect(addr);
+        third.set_read_timeout(Duration::from_millis(200));
+        assert!(third.send("third").is_err(), "all threads should be busy");
+
+        drop(first);
+        third.set_read_timeout(Duration::from_secs(2));
+        let mut response = String::new();
+        third.reader.read_line(&mut response).unwrap();
+        assert_eq!("third\n", response);
+    }
+}
@@ -0,0 +1,93 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 27 Smart pointer
+//! ----------------
+//!
+//! Welcome to twenty-seventh step of this Rust workshop.
+//!
+//! This step focuses on implementing your own versions of `Box`, `Rc`/`Weak` and `RefCell`, used in [13-memory](../13_memory/index.html). It builds on raw pointers and `unsafe` code from [19-unsafe](../19_unsafe/index.html).
+//!
+//! ## Smart pointer
+//!
+//! A _smart pointer_ is a struct which behaves like a reference, but has extra capabilities: owning heap memory, counting references, checking borrows, ... Two traits make it happen:
+//!
+//! * [`Deref`](https://doc.rust-lang.org/std/ops/trait.Deref.html) (and `DerefMut`): gives access to pointed value with `*`, and enables _deref coercion_ (`&MyBox<String>` can be given where `&str` is expected) and method calls on pointed value,
+//! * [`Drop`](https://doc.rust-lang.org/std/ops/trait.Drop.html): releases resources when pointer goes out of scope.
+//!
+//! ```rust
+//! use std::ops::Deref;
+//!
+//! struct Wrapper<T>(T);
+//!
+//! impl<T> Deref for Wrapper<T> {
+//!     type Target = T;
+//!
+//!     fn deref(&self) -> &T {
+//!         &self.0
+//!     }
+//! }
+//!
+//! let wrapped = Wrapper(String::from("rust"));
+//! assert_eq!(4, wrapped.len()); // Calls String::len
+//! ```
+//!
+//! ## Allocation
+//!
+//! [`std::alloc`](https://doc.rust-lang.org/std/alloc/index.html) gives access to heap allocator. Memory is described by a [`Layout`](https://doc.rust-lang.org/std/alloc/struct.Layout.html) (size and alignment), and is **uninitialized**: value must be written with `ptr::write`, which doesn't try to drop previous (garbage) content.
+//!
+//! ```rust
+//! use std::alloc::{alloc, dealloc, Layout};
+//! use std::ptr;
+//!
+//! let layout = Layout::new::<u64>();
+//! unsafe {
+//!     let pointer = alloc(layout) as *mut u64;
+//!     assert!(!pointer.is_null());
+//!     ptr::write(pointer, 42);
+//!     assert_eq!(42, *pointer);
+//!     dealloc(pointer as *mut u8, layout);
+//! }
+//! ```
+//!
+//! _Note: allocating zero bytes is undefined behaviour. Zero-sized types (like `()`) need no memory at all: [`NonNull::dangling()`](https://doc.rust-lang.org/std/ptr/struct.NonNull.html#method.dangling) gives a valid pointer for them._
+//!
+//! To drop a value behind a raw pointer, use `ptr::drop_in_place`. To prevent a value from being dropped, use `mem::forget` or wrap it into [`ManuallyDrop`](https://doc.rust-lang.org/std/mem/struct.ManuallyDrop.html).
+//!
+//! ## Reference counting
+//!
+//! `Rc` stores counters next to value, into a single heap block shared by all pointers. Cloning increments strong counter, dropping decrements it, and value is dropped when it reaches zero. Weak references only keep **memory** alive: block is freed when there is no strong nor weak reference anymore.
+//!
+//! ```text
+//!  MyRc ──┐
+//!  MyRc ──┼──> [ strong: 2 | weak: 1 | value ]
+//! MyWeak ─┘
+//! ```
+//!
+//! Beware dropping value may itself drop the last weak reference (e.g. a node pointing to itself). Standard library avoids freeing memory too early by making all strong references share one extra weak reference.
+//!
+//! _Note: as counters aren't updated atomically, `Rc` must not be `Send`. Raw pointers aren't `Send` nor `Sync`, so a struct containing one gets the right behaviour by default. `Arc` is the same with atomic counters (see [17-shared_state](../17_shared_state/index.html))._
+//!
+//! ## Interior mutability
+//!
+//! Mutating data behind `&T` is undefined behaviour... except through [`UnsafeCell`](https://doc.rust-lang.org/std/cell/struct.UnsafeCell.html), the building block of `Cell`, `RefCell`, `Mutex`, ... Its `get()` method returns a `*mut T` from a `&self`. It's then up to you to guarantee borrowing rules.
+//!
+//! `RefCell` checks them at runtime with a counter: number of active shared borrows, or a special value when mutably borrowed. Borrowing returns a _guard_, which releases borrow when dropped (see `MutexGuard` in [17-shared_state](../17_shared_state/index.html)):
+//!
+//! ```rust,should_panic
+//! use std::cell::RefCell;
+//!
+//! let cell = RefCell::new(42);
+//! let shared = cell.borrow();
+//! let mutable = cell.borrow_mut(); // Panics: already borrowed
+//! ```
+//!
+//! ## PhantomData
+//!
+//! When a struct only holds a raw pointer, compiler doesn't know it owns a `T` (for drop checking) nor that a guard borrows a cell. Use [`PhantomData`](https://doc.rust-lang.org/std/marker/struct.PhantomData.html) to tell it, as seen in [19-unsafe](../19_unsafe/index.html).
+//!
+//! Get more info: [The Rustonomicon](https://doc.rust-lang.org/nomicon/) and [Learning Rust With Entirely Too Many Linked Lists](https://rust-unofficial.github.io/too-many-lists/).
+
+#[cfg(test)]
+mod tests;
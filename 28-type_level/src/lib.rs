@@ -0,0 +1,123 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 28 Type level
+//! -------------
+//!
+//! Welcome to twenty-eighth step of this Rust workshop.
+//!
+//! This step focuses on making compiler check more than usual: const generics, marker types and `PhantomData`. Goal is to turn runtime errors into compilation errors.
+//!
+//! _Note: some tests of this step check your code does **not** compile in given situations. They call `rustc` on your files, so they must stay self-contained._
+//!
+//! ## Const generics
+//!
+//! Besides types (see [08-generics](../08_generics/index.html)), generic parameters can be constant values, declared with `const N: usize`. Arrays `[T; N]` are the most common example:
+//!
+//! ```rust
+//! fn sum<const N: usize>(values: [i32; N]) -> i32 {
+//!     values.iter().sum()
+//! }
+//!
+//! assert_eq!(6, sum([1, 2, 3]));   // N = 3
+//! assert_eq!(10, sum([1, 2, 3, 4])); // N = 4
+//! ```
+//!
+//! Structs can have const parameters too. Each value is a different type, so sizes are checked at compile time:
+//!
+//! ```compile_fail
+//! struct Buffer<const N: usize> {
+//!     data: [u8; N],
+//! }
+//!
+//! fn copy<const N: usize>(from: &Buffer<N>, to: &mut Buffer<N>) {
+//!     to.data = from.data;
+//! }
+//!
+//! let from = Buffer { data: [0; 4] };
+//! let mut to = Buffer { data: [0; 8] };
+//! copy(&from, &mut to); // Compilation error: expected `Buffer<4>`, found `Buffer<8>`
+//! ```
+//!
+//! An `impl` block may only apply to some values, like `impl<const N: usize> Square<N, N>`. As with type parameters, same const parameter appearing twice means both values must be equal.
+//!
+//! _Note: const parameters are limited to integers, `bool` and `char`, and can't be computed from other parameters yet (no `[T; N + 1]`)._
+//!
+//! ## Marker types
+//!
+//! A struct without any field carries no data, and takes no memory. But as a type, it carries information to compiler. Combined with generics, it encodes a _state_ into a type: methods are then only available in some states. That's the _type-state_ pattern:
+//!
+//! ```rust
+//! struct Open;
+//! struct Closed;
+//!
+//! struct Door<State> {
+//!     state: State,
+//! }
+//!
+//! impl Door<Closed> {
+//!     fn open(self) -> Door<Open> {
+//!         Door { state: Open }
+//!     }
+//! }
+//!
+//! impl Door<Open> {
+//!     fn walk_through(&self) {}
+//! }
+//!
+//! let door = Door { state: Closed };
+//! door.open().walk_through(); // door.walk_through() doesn't compile
+//! ```
+//!
+//! Methods consuming `self` and returning another type ensure an old state can't be used anymore.
+//!
+//! ## PhantomData
+//!
+//! A generic parameter must be used by a field. When type is only a marker, [`std::marker::PhantomData`](https://doc.rust-lang.org/std/marker/struct.PhantomData.html) acts as a zero-sized field "using" it:
+//!
+//! ```rust
+//! use std::marker::PhantomData;
+//!
+//! struct Id<T> {
+//!     value: u64,
+//!     entity: PhantomData<T>,
+//! }
+//!
+//! struct User;
+//! struct Order;
+//!
+//! fn find_user(id: Id<User>) {}
+//!
+//! let user: Id<User> = Id { value: 42, entity: PhantomData };
+//! let order: Id<Order> = Id { value: 42, entity: PhantomData };
+//! find_user(user); // find_user(order) doesn't compile, although both contain 42
+//! ```
+//!
+//! That's the _newtype_ pattern: wrapping a primitive into a dedicated type prevents mixing up values (meters and seconds, user and order ids, ...). It costs nothing at runtime.
+//!
+//! ## Associated constants
+//!
+//! Traits can declare constants, implemented by each type. Combined with marker types, they attach values to types:
+//!
+//! ```rust
+//! trait Currency {
+//!     const SYMBOL: &'static str;
+//! }
+//!
+//! struct Euro;
+//! impl Currency for Euro {
+//!     const SYMBOL: &'static str = "€";
+//! }
+//!
+//! fn price<C: Currency>(amount: u32) -> String {
+//!     format!("{} {}", amount, C::SYMBOL)
+//! }
+//!
+//! assert_eq!("42 €", price::<Euro>(42));
+//! ```
+//!
+//! Get more info: [rust-reference/const-generics](https://doc.rust-lang.org/reference/items/generics.html#const-generics) and [The Typestate Pattern in Rust](https://cliffle.com/blog/rust-typestate/).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,185 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Types have to be written into `matrix.rs`, `car.rs` and `units.rs` files.
+//!
+
+mod matrix;
+mod car;
+mod units;
+
+#[path = "../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `snippet` (as `main` body) along with `source`.
+fn compile(name: &str, source: &str, snippet: &str) -> compiler::Compilation {
+    compiler::compile(name, &compiler::program(source, snippet), &[])
+}
+
+const MATRIX: &str = include_str!("tests/matrix.rs");
+const CAR: &str = include_str!("tests/car.rs");
+const UNITS: &str = include_str!("tests/units.rs");
+
+mod matrix_should {
+    use super::*;
+    use super::matrix::*;
+
+    #[test]
+    fn give_access_to_elements() {
+        let matrix = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(2, matrix.get(0, 1));
+        assert_eq!(4, matrix.get(1, 0));
+        assert_eq!(0, Matrix::<i32, 2, 2>::zero().get(1, 1));
+    }
+
+    #[test]
+    fn transpose() {
+        let matrix: Matrix<i32, 3, 2> = Matrix::new([[1, 2, 3], [4, 5, 6]]).transpose();
+        assert_eq!(Matrix::new([[1, 4], [2, 5], [3, 6]]), matrix);
+    }
+
+    #[test]
+    fn multiply_matching_dimensions() {
+        let left = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let right = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+        let product: Matrix<i32, 2, 2> = left * right;
+        assert_eq!(Matrix::new([[58, 64], [139, 154]]), product);
+    }
+
+    #[test]
+    fn multiply_by_identity() {
+        let matrix = Matrix::new([[1.5, 2.0], [3.0, 4.0]]);
+        assert_eq!(matrix, matrix * Matrix::identity());
+        assert_eq!(matrix, Matrix::identity() * matrix);
+    }
+
+    #[test]
+    fn compile_when_multiplying_matching_dimensions() {
+        compile("mul", MATRIX, "let product: Matrix<i32, 2, 4> = Matrix::<i32, 2, 3>::zero() * Matrix::<i32, 3, 4>::zero();").assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_multiplying_mismatching_dimensions() {
+        compile("mul_match", MATRIX, "let product: Matrix<i32, 2, 4> = Matrix::<i32, 2, 3>::zero() * Matrix::<i32, 3, 4>::zero();").assert_success();
+        compile("mul_mismatch", MATRIX, "let product = Matrix::<i32, 2, 3>::zero() * Matrix::<i32, 2, 3>::zero();")
+            .assert_error("E0308");
+    }
+
+    #[test]
+    fn compile_identity_of_square_matrix() {
+        compile("identity", MATRIX, "let identity = Matrix::<i32, 3, 3>::identity();").assert_success();
+    }
+
+    #[test]
+    fn not_compile_identity_of_non_square_matrix() {
+        compile("identity_square", MATRIX, "let identity = Matrix::<i32, 3, 3>::identity();").assert_success();
+        compile("identity_non_square", MATRIX, "let identity = Matrix::<i32, 2, 3>::identity();")
+            .assert_error("no function or associated item named `identity` found for struct `Matrix<i32, 2, 3>`");
+    }
+}
+
+mod car_builder_should {
+    use super::*;
+    use super::car::*;
+
+    #[test]
+    fn build_car_using_a_fluent_interface() {
+        let car = CarBuilder::new().brand("Tesla").model("Model S").build();
+        assert_eq!("Tesla", car.brand());
+        assert_eq!("Model S", car.model());
+    }
+
+    #[test]
+    fn build_car_whatever_order() {
+        let car = CarBuilder::new().model("Logan").brand("Dacia").build();
+        assert_eq!(("Dacia", "Logan"), (car.brand(), car.model()));
+    }
+
+    #[test]
+    fn compile_when_brand_and_model_are_set() {
+        compile("build", CAR, r#"let car: Car = CarBuilder::new().brand("Audi").model("R8").build();"#).assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_building_without_model() {
+        compile("build_with_model", CAR, r#"let car: Car = CarBuilder::new().brand("Audi").model("R8").build();"#).assert_success();
+        compile("build_without_model", CAR, r#"let car = CarBuilder::new().brand("Audi").build();"#)
+            .assert_error("no method named `build` found for struct `CarBuilder<Brand, Missing>`");
+    }
+
+    #[test]
+    fn not_compile_when_building_without_brand() {
+        compile("build_with_brand", CAR, r#"let car: Car = CarBuilder::new().brand("Audi").model("R8").build();"#).assert_success();
+        compile("build_without_brand", CAR, r#"let car = CarBuilder::new().model("R8").build();"#)
+            .assert_error("no method named `build` found for struct `CarBuilder<Missing, Model>`");
+    }
+
+    #[test]
+    fn not_compile_when_setting_brand_twice() {
+        compile("brand_once", CAR, r#"let car: Car = CarBuilder::new().brand("Audi").model("R8").build();"#).assert_success();
+        compile("brand_twice", CAR, r#"let car = CarBuilder::new().brand("Audi").brand("BMW").model("R8").build();"#)
+            .assert_error("no method named `brand` found for struct `CarBuilder<Brand, Missing>`");
+    }
+}
+
+mod units_should {
+    use super::*;
+    use super::units::*;
+
+    #[test]
+    fn add_and_subtract_same_units() {
+        let distance = Quantity::<Meters>::new(100.0) + Quantity::new(20.0) - Quantity::new(5.0);
+        assert_eq!(115.0, distance.value());
+    }
+
+    #[test]
+    fn scale() {
+        assert_eq!(Quantity::<Seconds>::new(30.0), Quantity::new(10.0) * 3.0);
+    }
+
+    #[test]
+    fn compute_speed_and_distance() {
+        let speed = Quantity::<Meters>::new(100.0) / Quantity::<Seconds>::new(8.0);
+        assert_eq!(Quantity::<MetersPerSecond>::new(12.5), speed);
+        let distance: Quantity<Meters> = speed * Quantity::new(2.0);
+        assert_eq!(25.0, distance.value());
+    }
+
+    #[test]
+    fn display_with_unit_symbol() {
+        assert_eq!("42 m", Quantity::<Meters>::new(42.0).to_string());
+        assert_eq!("1.5 s", Quantity::<Seconds>::new(1.5).to_string());
+        assert_eq!("3 m/s", Quantity::<MetersPerSecond>::new(3.0).to_string());
+    }
+
+    #[test]
+    fn take_no_more_memory_than_value() {
+        assert_eq!(std::mem::size_of::<f64>(), std::mem::size_of::<Quantity<Meters>>());
+    }
+
+    #[test]
+    fn compile_when_adding_same_units() {
+        compile("add", UNITS, "let total = Quantity::<Meters>::new(1.0) + Quantity::<Meters>::new(2.0);").assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_adding_different_units() {
+        compile("add_match", UNITS, "let total = Quantity::<Meters>::new(1.0) + Quantity::<Meters>::new(2.0);").assert_success();
+        compile("add_mismatch", UNITS, "let total = Quantity::<Meters>::new(1.0) + Quantity::<Seconds>::new(2.0);")
+            .assert_error("E0308");
+    }
+
+    #[test]
+    fn not_compile_when_dividing_seconds_by_meters() {
+        compile("div_match", UNITS, "let speed = Quantity::<Meters>::new(1.0) / Quantity::<Seconds>::new(2.0);").assert_success();
+        compile("div_mismatch", UNITS, "let speed = Quantity::<Seconds>::new(1.0) / Quantity::<Meters>::new(2.0);")
+            .assert_error("cannot divide `Quantity<Seconds>` by `Quantity<Meters>`");
+    }
+
+    #[test]
+    fn not_compile_when_comparing_different_units() {
+        compile("eq_match", UNITS, "let same = Quantity::<Meters>::new(1.0) == Quantity::<Meters>::new(1.0);").assert_success();
+        compile("eq_mismatch", UNITS, "let same = Quantity::<Meters>::new(1.0) == Quantity::<Seconds>::new(1.0);")
+            .assert_error("E0308");
+    }
+}
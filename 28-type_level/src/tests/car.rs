@@ -0,0 +1,33 @@
+/// Built by `CarBuilder` (see 04-ownership).
+#[derive(Debug, PartialEq)]
+pub struct Car {
+    brand: String,
+    model: String,
+}
+
+impl Car {
+    pub fn brand(&self) -> &str {
+        &self.brand
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+/// Marks a missing brand or model.
+pub struct Missing;
+
+/// Brand which has been set.
+pub struct Brand(String);
+
+/// Model which has been set.
+pub struct Model(String);
+
+/// Builder whose type tells what has been set: `CarBuilder<Brand, Missing>` has a brand but no model.
+pub struct CarBuilder<B, M> {
+    brand: B,
+    model: M,
+}
+
+//TODO: implement `new()`, `brand()`, `model()` and `build()`, so that `build()` can only be called once brand and model have been set (each one once)
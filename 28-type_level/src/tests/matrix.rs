@@ -0,0 +1,29 @@
+use std::ops::{Add, Mul};
+
+/// Matrix of `R` rows and `C` columns, checked at compile time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T: Copy + Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn new(rows: [[T; C]; R]) -> Self {
+        Matrix { rows }
+    }
+
+    pub fn zero() -> Self {
+        Matrix { rows: [[T::default(); C]; R] }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.rows[row][col]
+    }
+
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::zero() //TODO
+    }
+}
+
+//TODO: implement `identity()`, only for square matrices
+
+//TODO: implement `Mul`, only for matching dimensions
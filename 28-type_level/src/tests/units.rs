@@ -0,0 +1,51 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+
+pub trait Unit {
+    const SYMBOL: &'static str;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Meters;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seconds;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetersPerSecond;
+
+impl Unit for Meters {
+    const SYMBOL: &'static str = "m";
+}
+
+impl Unit for Seconds {
+    const SYMBOL: &'static str = "s";
+}
+
+impl Unit for MetersPerSecond {
+    const SYMBOL: &'static str = "m/s";
+}
+
+/// Value of unit `U`. Unit only exists at compile time: a `Quantity` is just a `f64` in memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<U: Unit> {
+    value: f64,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> Quantity<U> {
+    pub fn new(value: f64) -> Self {
+        Quantity { value, unit: PhantomData }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+//TODO: add and subtract quantities of same unit, and scale them by a `f64`
+
+//TODO: divide meters by seconds, and multiply meters per second by seconds
+
+//TODO: display value followed by unit symbol
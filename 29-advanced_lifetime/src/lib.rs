@@ -0,0 +1,118 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 29 Advanced lifetime
+//! --------------------
+//!
+//! Welcome to twenty-ninth step of this Rust workshop.
+//!
+//! This step goes further than [05-lifetime](../05_lifetime/index.html): several lifetimes, iterators over borrowed data, `'static`, higher-ranked trait bounds and variance.
+//!
+//! _Note: some tests of this step check that sound code compiles, and that unsound code does **not**. They call `rustc` on `lifetimes.rs` file, so it must stay self-contained._
+//!
+//! ## Several lifetimes
+//!
+//! A single lifetime for all references is simple, but often too restrictive: compiler considers all of them must live as long as the shortest one. Distinct lifetimes tell which input a result really borrows:
+//!
+//! ```rust
+//! struct Prefixed<'t, 'p> {
+//!     text: &'t str,
+//!     prefix: &'p str,
+//! }
+//!
+//! impl<'t, 'p> Prefixed<'t, 'p> {
+//!     /// Result only borrows text
+//!     fn rest(&self) -> &'t str {
+//!         self.text.trim_start_matches(self.prefix)
+//!     }
+//! }
+//!
+//! let text = String::from("> quoted");
+//! let rest = {
+//!     let prefix = String::from("> ");
+//!     Prefixed { text: &text, prefix: &prefix }.rest()
+//! }; // prefix is dropped, but rest is still valid
+//! assert_eq!("quoted", rest);
+//! ```
+//!
+//! _Note: `'t: 'p` (read "`'t` outlives `'p`") declares a relation between two lifetimes. Similarly, `T: 'a` means all references inside `T` outlive `'a`._
+//!
+//! ## Iterating over borrowed data
+//!
+//! An iterator over borrowed data yields items borrowing **data**, not iterator itself. That's why `Item` uses struct lifetime, and items remain valid after iterator is dropped:
+//!
+//! ```rust
+//! struct Lines<'a> {
+//!     remaining: &'a str,
+//! }
+//!
+//! impl<'a> Iterator for Lines<'a> {
+//!     type Item = &'a str;
+//!
+//!     fn next(&mut self) -> Option<&'a str> { // Not tied to &mut self
+//!         if self.remaining.is_empty() {
+//!             return None;
+//!         }
+//!         let end = self.remaining.find('\n').unwrap_or(self.remaining.len());
+//!         let line = &self.remaining[..end];
+//!         self.remaining = &self.remaining[(end + 1).min(self.remaining.len())..];
+//!         Some(line)
+//!     }
+//! }
+//! ```
+//!
+//! With mutable references, it's harder: a `&'a mut [T]` can't be copied out of `&mut self`, as there would be two mutable references to the same data. It has to be **moved** out, for example with [`mem::take`](https://doc.rust-lang.org/std/mem/fn.take.html) or `mem::replace`, then split into non-overlapping parts with `split_at_mut`.
+//!
+//! ## 'static
+//!
+//! `&'static T` is a reference valid until program ends: string literals, constants, or leaked memory (`Box::leak`).
+//!
+//! As a **bound**, `T: 'static` means `T` contains no reference shorter than `'static`. Any owned type (`String`, `Vec<i32>`, ...) satisfies it. That's what `thread::spawn` requires, as a thread may outlive its caller (see [12-parallelism](../12_parallelism/index.html)):
+//!
+//! ```rust
+//! use std::thread;
+//!
+//! fn spawn_print<T: std::fmt::Debug + Send + 'static>(value: T) -> thread::JoinHandle<()> {
+//!     thread::spawn(move || println!("{:?}", value))
+//! }
+//!
+//! spawn_print(vec![1, 2, 3]).join().unwrap(); // Ok: Vec<i32> owns its data
+//! spawn_print("literal").join().unwrap();     // Ok: &'static str
+//! ```
+//!
+//! ## Higher-ranked trait bounds
+//!
+//! Sometimes a closure must accept references of **any** lifetime, including ones which only exist inside called function. Lifetime can't be a parameter chosen by caller then. That's written with a _higher-ranked trait bound_ (HRTB), `for<'a>`, read "for all `'a`":
+//!
+//! ```rust
+//! fn apply_to_trimmed<F>(text: &str, f: F) -> usize where F: for<'a> Fn(&'a str) -> usize {
+//!     let trimmed = text.trim().to_string(); // Local value
+//!     f(&trimmed)
+//! }
+//!
+//! assert_eq!(4, apply_to_trimmed("  rust  ", |text| text.len()));
+//! ```
+//!
+//! _Note: `Fn(&str) -> usize` is a shortcut for exactly that, thanks to elision rules. Explicit syntax is required when lifetimes appear into return type, or in other traits than `Fn*`._
+//!
+//! ## Variance
+//!
+//! A `&'static str` can be used where a `&'a str` is expected: `&'a T` is _covariant_ in `'a`, a longer lifetime can be shortened. But `&'a mut T` is _invariant_ in `T`: through a `&mut &'static str`, someone could write a short-lived reference where a `'static` one is expected.
+//!
+//! ```compile_fail
+//! fn assign<'a>(target: &mut &'a str, value: &'a str) {
+//!     *target = value;
+//! }
+//!
+//! let mut message: &'static str = "static";
+//! let local = String::from("local");
+//! assign(&mut message, &local); // Compilation error: `local` does not live long enough
+//! ```
+//!
+//! Invariance leads to a common pitfall: `&'a mut self` on a `Foo<'a>` borrows `self` mutably for its **whole** lifetime, so it can't be used anymore afterwards. Mutable borrow of `self` should almost always have its own (elided) lifetime.
+//!
+//! Get more info: [The Rustonomicon](https://doc.rust-lang.org/nomicon/subtyping.html) and [Common Rust Lifetime Misconceptions](https://github.com/pretzelhammer/rust-blog/blob/master/posts/common-rust-lifetime-misconceptions.md).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,266 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Code has to be fixed into `lifetimes.rs` file.
+//!
+
+mod lifetimes;
+
+use self::lifetimes::*;
+
+#[path = "../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `snippet` (as `main` body) along with `lifetimes.rs`.
+fn compile(name: &str, snippet: &str) -> compiler::Compilation {
+    compiler::compile(name, &compiler::program(include_str!("tests/lifetimes.rs"), snippet), &[])
+}
+
+mod highlighter_should {
+    use super::*;
+
+    #[test]
+    fn find_first_match() {
+        let highlighter = Highlighter::new("to be or not to be", "be");
+        assert_eq!(Some("be"), highlighter.first_match());
+        assert_eq!("to ", highlighter.before_match());
+    }
+
+    #[test]
+    fn return_none_when_no_match() {
+        let highlighter = Highlighter::new("to be or not to be", "see");
+        assert_eq!(None, highlighter.first_match());
+        assert_eq!("to be or not to be", highlighter.before_match());
+    }
+
+    #[test]
+    fn compile_when_pattern_is_dropped_before_match() {
+        compile("pattern_dropped", r#"
+            let text = String::from("hello world");
+            let (found, before) = {
+                let pattern = String::from("wor");
+                let highlighter = Highlighter::new(&text, &pattern);
+                (highlighter.first_match(), highlighter.before_match())
+            };
+            println!("{:?} {}", found, before);
+        "#).assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_text_is_dropped_before_match() {
+        compile("pattern_dropped", r#"
+            let text = String::from("hello world");
+            let (found, before) = {
+                let pattern = String::from("wor");
+                let highlighter = Highlighter::new(&text, &pattern);
+                (highlighter.first_match(), highlighter.before_match())
+            };
+            println!("{:?} {}", found, before);
+        "#).assert_success();
+        compile("text_dropped", r#"
+            let found = {
+                let text = String::from("hello world");
+                Highlighter::new(&text, "wor").first_match()
+            };
+            println!("{:?}", found);
+        "#).assert_error("`text` does not live long enough");
+    }
+}
+
+mod chunks_should {
+    use super::*;
+
+    #[test]
+    fn return_nothing_when_empty() {
+        assert_eq!(None, Chunks::<i32>::new(&[], 2).next());
+    }
+
+    #[test]
+    fn split_into_chunks() {
+        let values = [1, 2, 3, 4, 5];
+        let chunks: Vec<&[i32]> = Chunks::new(&values, 2).collect();
+        assert_eq!(vec![&[1, 2][..], &[3, 4][..], &[5][..]], chunks);
+    }
+
+    #[test]
+    fn keep_chunks_after_iterator_is_dropped() {
+        let values = vec![1, 2, 3];
+        let first = {
+            let mut chunks = Chunks::new(&values, 2);
+            chunks.next().unwrap()
+        };
+        assert_eq!(&[1, 2], first);
+    }
+
+    #[test]
+    fn split_into_mutable_chunks() {
+        let mut values = [1, 2, 3, 4, 5];
+        for chunk in ChunksMut::new(&mut values, 2) {
+            chunk.reverse();
+        }
+        assert_eq!([2, 1, 4, 3, 5], values);
+    }
+
+    #[test]
+    fn give_many_mutable_chunks_at_once() {
+        let mut values = [1, 2, 3, 4];
+        {
+            let mut chunks = ChunksMut::new(&mut values, 2);
+            let (first, second) = (chunks.next().unwrap(), chunks.next().unwrap());
+            first.swap_with_slice(second);
+        }
+        assert_eq!([3, 4, 1, 2], values);
+    }
+
+    #[test]
+    fn not_compile_when_input_is_modified_while_chunk_is_alive() {
+        compile("chunk_dead", r#"
+            let mut values = vec![1, 2, 3];
+            let first = Chunks::new(&values, 2).next().unwrap();
+            println!("{:?}", first);
+            values.push(4);
+        "#).assert_success();
+        compile("chunk_alive", r#"
+            let mut values = vec![1, 2, 3];
+            let first = Chunks::new(&values, 2).next().unwrap();
+            values.push(4);
+            println!("{:?}", first);
+        "#).assert_error("cannot borrow `values` as mutable because it is also borrowed as immutable");
+    }
+
+    #[test]
+    fn not_compile_when_input_is_read_while_mutable_chunk_is_alive() {
+        compile("chunk_mut_dead", r#"
+            let mut values = vec![1, 2, 3];
+            let first = ChunksMut::new(&mut values, 2).next().unwrap();
+            first[0] = 0;
+            println!("{:?}", values);
+        "#).assert_success();
+        compile("chunk_mut_alive", r#"
+            let mut values = vec![1, 2, 3];
+            let first = ChunksMut::new(&mut values, 2).next().unwrap();
+            println!("{:?}", values);
+            first[0] = 0;
+        "#).assert_error("cannot borrow `values` as immutable because it is also borrowed as mutable");
+    }
+}
+
+mod static_should {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn run_owned_data_in_background() {
+        let words = vec![String::from("hello"), String::from("world")];
+        let handle = run_in_background(move || words.join(" "));
+        assert_eq!("hello world", handle.join().unwrap());
+    }
+
+    #[test]
+    fn run_task_in_another_thread() {
+        let caller = thread::current().id();
+        assert_ne!(caller, run_in_background(|| thread::current().id()).join().unwrap());
+    }
+
+    #[test]
+    fn share_leaked_string_with_threads() {
+        let message: &'static str = leak(format!("{} {}", "leaked", 42));
+        let handles: Vec<_> = (0..3).map(|_| run_in_background(move || message.len())).collect();
+        for handle in handles {
+            assert_eq!(9, handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn not_compile_when_task_borrows_local_data() {
+        compile("owned_task", r#"
+            let words = vec![String::from("hello")];
+            let handle = run_in_background(move || words.len());
+            handle.join().unwrap();
+        "#).assert_success();
+        compile("borrowed_task", r#"
+            let words = vec![String::from("hello")];
+            let handle = run_in_background(|| words.len());
+            handle.join().unwrap();
+        "#).assert_error("E0373");
+    }
+}
+
+mod count_matching_should {
+    use super::*;
+
+    #[test]
+    fn give_lowercase_words_to_predicate() {
+        let words = ["Rust", "RUST", "rusty", "Ferris"];
+        assert_eq!(2, count_matching(&words, |word| word == "rust"));
+        assert_eq!(3, count_matching(&words, |word| word.starts_with("rust")));
+    }
+
+    #[test]
+    fn accept_functions_taking_any_str() {
+        fn is_short(word: &str) -> bool {
+            word.len() < 5
+        }
+        assert_eq!(1, count_matching(&["Rust", "Ferris"], is_short));
+    }
+}
+
+mod cache_should {
+    use super::*;
+
+    #[test]
+    fn insert_many_entries_and_read_them() {
+        compile("cache_many", r#"
+            let (first, second) = (String::from("first"), String::from("second"));
+            let mut cache = Cache::new();
+            cache.insert(&first);
+            cache.insert(&second);
+            assert_eq!(&["first", "second"], cache.entries());
+        "#).assert_success();
+    }
+
+    #[test]
+    fn accept_static_and_local_entries() {
+        compile("cache_static_local", r#"
+            let local = String::from("local");
+            let mut cache = Cache::new();
+            cache.insert("static");
+            cache.insert(&local);
+            assert_eq!(2, cache.entries().len());
+        "#).assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_entry_is_dropped_before_cache() {
+        compile("entry_alive", r#"
+            let entry = String::from("entry");
+            let mut cache = Cache::new();
+            cache.insert(&entry);
+            println!("{:?}", cache.entries());
+        "#).assert_success();
+        compile("entry_dropped", r#"
+            let mut cache = Cache::new();
+            {
+                let entry = String::from("entry");
+                cache.insert(&entry);
+            }
+            println!("{:?}", cache.entries());
+        "#).assert_error("`entry` does not live long enough");
+    }
+}
+
+#[test]
+fn compile_sound_usages() {
+    compile("sound", r#"
+        let text = String::from("hello world");
+        let found = Highlighter::new(&text, &String::from("wor")).first_match();
+        let mut values = vec![1, 2, 3];
+        let chunks: Vec<&mut [i32]> = ChunksMut::new(&mut values, 2).collect();
+        run_in_background(move || text.len()).join().unwrap();
+        count_matching(&["Rust"], |word| word == "rust");
+        let mut cache = Cache::new();
+        cache.insert("first");
+        cache.insert("second");
+        cache.entries();
+    "#).assert_success();
+}
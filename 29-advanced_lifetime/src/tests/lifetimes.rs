@@ -0,0 +1,110 @@
+use std::mem;
+use std::thread::{self, JoinHandle};
+
+/// Finds `pattern` into `text`. Found slices borrow `text` only, so `pattern` may be dropped before them.
+//TODO: allow `pattern` to be dropped before found slices
+pub struct Highlighter<'a> {
+    text: &'a str,
+    pattern: &'a str,
+}
+
+impl<'a> Highlighter<'a> {
+    pub fn new(text: &'a str, pattern: &'a str) -> Self {
+        Highlighter { text, pattern }
+    }
+
+    pub fn first_match(&self) -> Option<&'a str> {
+        self.text.find(self.pattern).map(|start| &self.text[start..start + self.pattern.len()])
+    }
+
+    /// Returns text before first match, or whole text when not found.
+    pub fn before_match(&self) -> &'a str {
+        match self.text.find(self.pattern) {
+            Some(start) => &self.text[..start],
+            None => self.text,
+        }
+    }
+}
+
+/// Iterates over `size` elements long slices of `slice`. Last one may be shorter.
+pub struct Chunks<'a, T: 'a> {
+    slice: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    pub fn new(slice: &'a [T], size: usize) -> Self {
+        assert!(size > 0, "chunk size must not be zero");
+        Chunks { slice, size }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        None //TODO
+    }
+}
+
+/// Same as `Chunks`, but with mutable slices.
+pub struct ChunksMut<'a, T: 'a> {
+    slice: &'a mut [T],
+    size: usize,
+}
+
+impl<'a, T> ChunksMut<'a, T> {
+    pub fn new(slice: &'a mut [T], size: usize) -> Self {
+        assert!(size > 0, "chunk size must not be zero");
+        ChunksMut { slice, size }
+    }
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        None //TODO: hint, `mem::take` replaces a value by its default one
+    }
+}
+
+/// Runs `task` into another thread.
+pub fn run_in_background<F, T>(task: F) -> JoinHandle<T> where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static {
+    let result = task();
+    thread::spawn(move || result) //TODO: run `task` itself into spawned thread
+}
+
+/// Gives up ownership of `text`, which then lives until program ends.
+pub fn leak(text: String) -> &'static str {
+    "" //TODO
+}
+
+/// Counts words matching `predicate`, which is given lowercase version of each word.
+pub fn count_matching<'a, F>(words: &[&'a str], predicate: F) -> usize where
+    F: Fn(&'a str) -> bool {
+    words.iter()
+        .filter(|word| predicate(word)) //TODO: give `word.to_lowercase()` to predicate (lifetime `'a` must be changed)
+        .count()
+}
+
+/// Collects borrowed strings.
+pub struct Cache<'a> {
+    entries: Vec<&'a str>,
+}
+
+impl<'a> Cache<'a> {
+    pub fn new() -> Self {
+        Cache { entries: vec![] }
+    }
+
+    //TODO: fix `cache_should::insert_many_entries_and_read_them`
+    pub fn insert(&'a mut self, entry: &'a str) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[&'a str] {
+        &self.entries
+    }
+}
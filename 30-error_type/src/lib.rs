@@ -0,0 +1,124 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 30 Error type
+//! -------------
+//!
+//! Welcome to thirtieth step of this Rust workshop.
+//!
+//! This step focuses on writing idiomatic error types. [09-error_management](../09_error_management/index.html) used plain enums; here errors implement [`std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html), so they can be displayed, chained and mixed together.
+//!
+//! ## Error trait
+//!
+//! An error type implements `Debug` (for developers) and `Display` (for users), then `Error` itself. All methods of `Error` are provided, so an empty `impl` block is enough:
+//!
+//! ```rust
+//! use std::error::Error;
+//! use std::fmt;
+//!
+//! #[derive(Debug)]
+//! struct EmptyName;
+//!
+//! impl fmt::Display for EmptyName {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         write!(f, "name must not be empty")
+//!     }
+//! }
+//!
+//! impl Error for EmptyName {}
+//!
+//! let error: &dyn Error = &EmptyName;
+//! assert_eq!("name must not be empty", error.to_string());
+//! ```
+//!
+//! _Note: by convention, messages are lowercase without trailing punctuation, as they may be embedded into other messages._
+//!
+//! ## Source chain
+//!
+//! When an error is caused by another one, [`source()`](https://doc.rust-lang.org/std/error/trait.Error.html#method.source) returns it. Each error only describes its **own** level, and caller walks down the chain to render the whole story:
+//!
+//! ```text
+//! invalid color 'red' at line 3
+//! caused by: invalid component
+//! caused by: number too large to fit in target type
+//! ```
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use std::fmt;
+//! # use std::num::ParseIntError;
+//! #[derive(Debug)]
+//! struct AgeError(ParseIntError);
+//!
+//! impl fmt::Display for AgeError {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         write!(f, "invalid age") // Don't repeat source message here
+//!     }
+//! }
+//!
+//! impl Error for AgeError {
+//!     fn source(&self) -> Option<&(dyn Error + 'static)> {
+//!         Some(&self.0)
+//!     }
+//! }
+//!
+//! let error = AgeError("-1".parse::<u8>().unwrap_err());
+//! assert_eq!("invalid digit found in string", error.source().unwrap().to_string());
+//! ```
+//!
+//! A source can be turned back into its concrete type with `downcast_ref::<T>()`, which returns `None` when types don't match.
+//!
+//! ## Conversion and context
+//!
+//! As seen in [24-io](../24_io/index.html), implementing `From` lets `?` operator convert errors automatically. It fits when underlying error is enough to describe what happened. Otherwise, use `map_err` to add _context_ (a file path, a line number, ...):
+//!
+//! ```rust,no_run
+//! # use std::{fs, io, path::{Path, PathBuf}};
+//! enum LoadError {
+//!     Io { path: PathBuf, source: io::Error },
+//! }
+//!
+//! fn load(path: &Path) -> Result<String, LoadError> {
+//!     fs::read_to_string(path).map_err(|source| LoadError::Io { path: path.to_path_buf(), source })
+//! }
+//! ```
+//!
+//! A generic wrapper, holding a message and a boxed source, adds context to any error. An _extension trait_ (a trait implemented for an existing type, here `Result`) makes it read nicely: `parse(text).context("cannot load theme")?`.
+//!
+//! ## Boxed errors
+//!
+//! `Box<dyn Error>` holds any error: `?` converts any `Error` into it, as well as `&str` and `String`. Adding `Send + Sync` allows to move it across threads (see [12-parallelism](../12_parallelism/index.html)). `main` may return it: on error, it's printed with `Debug` format and program exits with code `1`.
+//!
+//! ```rust,no_run
+//! use std::error::Error;
+//!
+//! fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+//!     let content = std::fs::read_to_string("numbers.txt")?; // io::Error
+//!     let first = content.lines().next().ok_or("empty file")?; // &str
+//!     let number: i32 = first.parse()?; // ParseIntError
+//!     println!("{}", number);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! It's very convenient for applications, but caller can't `match` on error anymore. Libraries generally expose their own enums.
+//!
+//! _Note: tests may also return `Result`, and then use `?`._
+//!
+//! ## Backtrace
+//!
+//! [`std::backtrace::Backtrace`](https://doc.rust-lang.org/std/backtrace/struct.Backtrace.html) records call stack of current thread, to tell **where** an error was created. `Backtrace::capture()` only does it when `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variable is set, as capturing is slow. `Backtrace::force_capture()` always does.
+//!
+//! ```rust
+//! use std::backtrace::{Backtrace, BacktraceStatus};
+//!
+//! let backtrace = Backtrace::force_capture();
+//! assert_eq!(BacktraceStatus::Captured, backtrace.status());
+//! println!("{}", backtrace);
+//! ```
+//!
+//! Get more info: [rust-by-example/multiple_error_types](https://doc.rust-lang.org/rust-by-example/error/multiple_error_types.html), then [anyhow](https://crates.io/crates/anyhow) and [thiserror](https://crates.io/crates/thiserror) crates, which remove most of this boilerplate.
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,393 @@
+#![allow(dead_code)]
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+
+#[path = "../../common/compiler.rs"]
+mod compiler;
+
+/// File of system temporary directory, created with given content and removed when dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn with_content(name: &str, content: &str) -> Self {
+        let path = compiler::temp_path(&format!("workshop_30_error_type_{}", name));
+        fs::write(&path, content).unwrap();
+        TempFile { path }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Error which can be sent to other threads, as used by `main`.
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Renders `error` followed by all its sources, one per line prefixed by `caused by: `.
+fn report(error: &dyn Error) -> String {
+    error.to_string() //TODO: append sources
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum ParseColorError {
+    ComponentCount(usize),
+    InvalidComponent(ParseIntError),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(()) //TODO
+    }
+}
+
+impl Error for ParseColorError {
+    //TODO: expose source
+}
+
+//TODO: convert `ParseIntError` into `ParseColorError`
+
+/// Parses a color written as `red, green, blue` (e.g. `255, 128, 0`).
+fn parse_color(text: &str) -> Result<Color, ParseColorError> {
+    Ok(Color { red: 0, green: 0, blue: 0 }) //TODO: use `?` operator
+}
+
+mod parse_color_should {
+    use super::*;
+
+    #[test]
+    fn parse_components() {
+        assert_eq!(Ok(Color { red: 255, green: 128, blue: 0 }), parse_color("255, 128, 0"));
+        assert_eq!(Ok(Color { red: 1, green: 2, blue: 3 }), parse_color("1,2,3"));
+    }
+
+    #[test]
+    fn report_component_count() {
+        let error = parse_color("255, 128").unwrap_err();
+        assert_eq!(ParseColorError::ComponentCount(2), error);
+        assert_eq!("expected 3 components, found 2", error.to_string());
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn expose_parse_int_error_as_source() {
+        let error = parse_color("255, 300, 0").unwrap_err();
+        assert_eq!("invalid component", error.to_string());
+        let source = error.source().expect("missing source");
+        assert_eq!("number too large to fit in target type", source.to_string());
+        assert!(source.downcast_ref::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn render_chain() {
+        assert_eq!("invalid component\ncaused by: invalid digit found in string", report(&parse_color("1, two, 3").unwrap_err()));
+        assert_eq!("invalid component\ncaused by: cannot parse integer from empty string", report(&parse_color("1, , 3").unwrap_err()));
+    }
+}
+
+#[derive(Debug)]
+enum PaletteError {
+    Io { path: PathBuf, source: io::Error },
+    Syntax { line: usize },
+    Color { line: usize, name: String, source: ParseColorError },
+}
+
+impl fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(()) //TODO
+    }
+}
+
+impl Error for PaletteError {
+    //TODO: expose source
+}
+
+/// Parses `name = red, green, blue` lines. Blank lines are ignored.
+fn parse_palette(input: &str) -> Result<HashMap<String, Color>, PaletteError> {
+    let mut palette = HashMap::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=');
+        let name = parts.next().unwrap().trim();
+        let value = parts.next().ok_or(PaletteError::Syntax { line: index + 1 })?;
+        let color = parse_color(value).map_err(|source| PaletteError::Color { line: index + 1, name: String::from(name), source })?;
+        palette.insert(String::from(name), color);
+    }
+    Ok(palette)
+}
+
+/// Reads then parses palette file at `path`.
+fn load_palette(path: &Path) -> Result<HashMap<String, Color>, PaletteError> {
+    Ok(HashMap::new()) //TODO: keep path into error
+}
+
+mod palette_should {
+    use super::*;
+
+    #[test]
+    fn parse_colors() {
+        let palette = parse_palette("black = 0, 0, 0\n\norange=255,128,0\n").unwrap();
+        assert_eq!(2, palette.len());
+        assert_eq!(Some(&Color { red: 0, green: 0, blue: 0 }), palette.get("black"));
+        assert_eq!(Some(&Color { red: 255, green: 128, blue: 0 }), palette.get("orange"));
+    }
+
+    #[test]
+    fn report_syntax_error_without_source() {
+        let error = parse_palette("black = 0, 0, 0\nwhite 255, 255, 255").unwrap_err();
+        assert_eq!("missing '=' at line 2", report(&error));
+    }
+
+    #[test]
+    fn render_whole_chain_of_invalid_color() {
+        let error = parse_palette("black = 0, 0, 0\n\nred = 256, 0, 0").unwrap_err();
+        assert_eq!(
+            "invalid color 'red' at line 3\n\
+             caused by: invalid component\n\
+             caused by: number too large to fit in target type",
+            report(&error)
+        );
+    }
+
+    #[test]
+    fn walk_down_sources() {
+        let error = parse_palette("grey = 128, 128").unwrap_err();
+        let source = error.source().expect("missing source");
+        assert_eq!(Some(&ParseColorError::ComponentCount(2)), source.downcast_ref::<ParseColorError>());
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn load_file() {
+        let file = TempFile::with_content("load.txt", "white = 255, 255, 255\n");
+        let palette = load_palette(&file.path).unwrap();
+        assert_eq!(Some(&Color { red: 255, green: 255, blue: 255 }), palette.get("white"));
+    }
+
+    #[test]
+    fn report_io_error_with_path() {
+        let path = env::temp_dir().join("workshop_30_error_type_missing/palette.txt");
+        let error = load_palette(&path).unwrap_err();
+        let report = report(&error);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(2, lines.len());
+        assert_eq!(format!("cannot read palette '{}'", path.display()), lines[0]);
+        assert!(lines[1].starts_with("caused by: "), "unexpected report: {}", report);
+        let source = error.source().and_then(|source| source.downcast_ref::<io::Error>()).expect("missing io::Error source");
+        assert_eq!(io::ErrorKind::NotFound, source.kind());
+    }
+}
+
+/// Error adding a message on top of another error, kept as source.
+#[derive(Debug)]
+struct Context {
+    message: String,
+    source: BoxError,
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for Context {
+    //TODO: expose source
+}
+
+/// Adds context to errors of any `Result`.
+trait ResultExt<T> {
+    /// Wraps error into a `Context` with given `message`.
+    fn context(self, message: &str) -> Result<T, Context>;
+
+    /// Same as `context`, but message is only built when there is an error.
+    fn with_context<F: FnOnce() -> String>(self, message: F) -> Result<T, Context>;
+}
+
+impl<T, E: Error + Send + Sync + 'static> ResultExt<T> for Result<T, E> {
+    fn context(self, message: &str) -> Result<T, Context> {
+        self.map_err(|_| Context { message: String::new(), source: BoxError::from("") }) //TODO
+    }
+
+    fn with_context<F: FnOnce() -> String>(self, message: F) -> Result<T, Context> {
+        self.map_err(|_| Context { message: String::new(), source: BoxError::from("") }) //TODO
+    }
+}
+
+mod context_should {
+    use super::*;
+
+    #[test]
+    fn keep_ok_value() {
+        let result: Result<u8, ParseIntError> = "42".parse();
+        assert_eq!(42, result.context("unused").unwrap());
+    }
+
+    #[test]
+    fn wrap_error_as_source() {
+        let error = "forty-two".parse::<u8>().context("cannot read answer").unwrap_err();
+        assert_eq!("cannot read answer", error.to_string());
+        assert!(error.source().and_then(|source| source.downcast_ref::<ParseIntError>()).is_some());
+        assert_eq!("cannot read answer\ncaused by: invalid digit found in string", report(&error));
+    }
+
+    #[test]
+    fn build_message_lazily() {
+        let mut built = false;
+        let result: Result<u8, ParseIntError> = "7".parse();
+        result.with_context(|| { built = true; String::from("unused") }).unwrap();
+        assert!(!built);
+
+        let error = parse_color("7").with_context(|| format!("cannot parse '{}'", "7")).unwrap_err();
+        assert_eq!("cannot parse '7'\ncaused by: expected 3 components, found 1", report(&error));
+    }
+
+    #[test]
+    fn stack_contexts() {
+        let error = parse_palette("sky = 0, 0, blue")
+            .context("cannot load theme")
+            .context("cannot start application")
+            .unwrap_err();
+        assert_eq!(
+            "cannot start application\n\
+             caused by: cannot load theme\n\
+             caused by: invalid color 'sky' at line 1\n\
+             caused by: invalid component\n\
+             caused by: invalid digit found in string",
+            report(&error)
+        );
+    }
+}
+
+/// Prints color `name` of palette file at `path` as hexadecimal. Arguments are `<path> <name>`.
+fn run(args: &[&str]) -> Result<String, BoxError> {
+    Ok(String::new()) //TODO: use `?` operator with any error
+}
+
+mod run_should {
+    use super::*;
+
+    #[test]
+    fn print_color() -> Result<(), BoxError> {
+        let file = TempFile::with_content("run.txt", "orange = 255, 128, 0\n");
+        assert_eq!("#ff8000", run(&[file.path.to_str().unwrap(), "orange"])?);
+        Ok(())
+    }
+
+    #[test]
+    fn return_usage_message() {
+        let error = run(&["palette.txt"]).unwrap_err();
+        assert_eq!("usage: palette <path> <name>", error.to_string());
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn return_unknown_color_message() {
+        let file = TempFile::with_content("unknown.txt", "orange = 255, 128, 0\n");
+        let error = run(&[file.path.to_str().unwrap(), "purple"]).unwrap_err();
+        assert_eq!("unknown color 'purple'", report(&*error));
+    }
+
+    #[test]
+    fn keep_typed_error() {
+        let file = TempFile::with_content("typed.txt", "orange = 255, 128\n");
+        let error = run(&[file.path.to_str().unwrap(), "orange"]).unwrap_err();
+        match error.downcast_ref::<PaletteError>() {
+            Some(&PaletteError::Color { line: 1, .. }) => {},
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(
+            "invalid color 'orange' at line 1\n\
+             caused by: expected 3 components, found 2",
+            report(&*error)
+        );
+    }
+
+    #[test]
+    fn be_sent_to_another_thread() {
+        let handle = std::thread::spawn(|| run(&[]));
+        let error = handle.join().unwrap().unwrap_err();
+        assert_eq!("usage: palette <path> <name>", error.to_string());
+    }
+}
+
+/// Error recording a backtrace of where it was created.
+#[derive(Debug)]
+struct Traced {
+    error: BoxError,
+    backtrace: Backtrace,
+}
+
+impl Traced {
+    fn new<E: Into<BoxError>>(error: E) -> Self {
+        Traced { error: error.into(), backtrace: Backtrace::disabled() } //TODO: capture backtrace
+    }
+
+    fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+impl fmt::Display for Traced {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl Error for Traced {
+    //TODO: be transparent, `Traced` only adds a backtrace to `error`
+}
+
+mod traced_should {
+    use super::*;
+
+    #[inline(never)]
+    fn fail_deep_inside() -> Traced {
+        Traced::new(parse_palette("red = 255").unwrap_err())
+    }
+
+    #[test]
+    fn be_transparent() {
+        let traced = fail_deep_inside();
+        assert_eq!(
+            "invalid color 'red' at line 1\n\
+             caused by: expected 3 components, found 1",
+            report(&traced)
+        );
+    }
+
+    #[test]
+    fn capture_backtrace() {
+        let traced = fail_deep_inside();
+        assert_eq!(BacktraceStatus::Captured, traced.backtrace().status());
+        let backtrace = traced.backtrace().to_string();
+        assert!(backtrace.contains("fail_deep_inside"), "unexpected backtrace: {}", backtrace);
+    }
+
+    #[test]
+    fn wrap_messages() {
+        let traced = Traced::new("disk full");
+        assert_eq!("disk full", report(&traced));
+        assert_eq!(BacktraceStatus::Captured, traced.backtrace().status());
+    }
+}
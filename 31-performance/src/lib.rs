@@ -0,0 +1,73 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 31 Performance
+//! --------------
+//!
+//! Welcome to thirty-first step of this Rust workshop.
+//!
+//! This step focuses on measuring performance with a tiny benchmark harness, only built on standard library. It's then used to compare algorithms, collections (see [11-collections](../11_collections/index.html)) and ownership choices (see [04-ownership](../04_ownership/index.html)).
+//!
+//! _Note: timings depend on machine, load and compilation profile (tests are built without optimizations by default, `cargo test --release` enables them). That's why tests of this step only check **relative** speed-ups between two versions of a code._
+//!
+//! ## Measuring time
+//!
+//! [`std::time::Instant`](https://doc.rust-lang.org/std/time/struct.Instant.html) is a monotonic clock: unlike `SystemTime`, it never goes backward. `elapsed()` returns a [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html):
+//!
+//! ```rust
+//! use std::time::Instant;
+//!
+//! let start = Instant::now();
+//! let sum: u64 = (0..1_000).sum();
+//! println!("sum {} computed in {:?}", sum, start.elapsed());
+//! ```
+//!
+//! Optimizer may compute `sum` at compile time, or even remove code whose result is unused. [`std::hint::black_box`](https://doc.rust-lang.org/std/hint/fn.black_box.html) prevents it: compiler must assume given value is read, and returned one may be anything.
+//!
+//! ## Benchmarking
+//!
+//! A single measure isn't reliable: first runs are slower (CPU caches, memory allocation, ...), and other processes interfere. A benchmark harness:
+//!
+//! * runs code a few times without measuring it (_warm-up_),
+//! * takes several _samples_, each one timing many _iterations_ to measure short code,
+//! * builds inputs outside of timed section (_setup_), so only relevant code is measured,
+//! * summarizes samples with their _median_ and _percentiles_ (e.g. 90% of samples are faster than p90), which are less sensitive to outliers than mean.
+//!
+//! When comparing two versions of a code, alternating their samples ensures a noisy period affects both alike. Ratio of medians is then the _speed-up_.
+//!
+//! ## Complexity
+//!
+//! Biggest wins come from algorithms and data structures. Searching a sorted `Vec` scans all elements (O(n)), whereas [`binary_search`](https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search) halves remaining elements at each step (O(log n)). On a million elements, that's 20 comparisons instead of up to a million.
+//!
+//! Choice of collection matters as well:
+//!
+//! | Operation          | `Vec`      | `VecDeque` | `LinkedList`            |
+//! |--------------------|------------|------------|-------------------------|
+//! | Push/pop at end    | O(1)       | O(1)       | O(1)                    |
+//! | Push/pop at front  | O(n)       | O(1)       | O(1)                    |
+//! | Index              | O(1)       | O(1)       | O(n)                    |
+//! | Memory             | contiguous | contiguous | one allocation per item |
+//!
+//! `VecDeque` is a _ring buffer_: a `Vec` whose start moves, wrapping around at the end. `LinkedList` has the same complexity for a queue, but allocating each item and following pointers is much slower in practice. It's rarely the right choice.
+//!
+//! ## Cloning
+//!
+//! Cloning a `String` or a `Vec` allocates memory and copies its content. Cloning to avoid fighting with borrow checker is tempting, but returning references (see [05-lifetime](../05_lifetime/index.html)) is often much cheaper:
+//!
+//! ```rust
+//! fn longest_cloning(words: &[String]) -> Option<String> {
+//!     words.iter().cloned().max_by_key(|word| word.len()) // Clones every word
+//! }
+//!
+//! fn longest(words: &[String]) -> Option<&String> {
+//!     words.iter().max_by_key(|word| word.len()) // Clones nothing
+//! }
+//! ```
+//!
+//! _Note: always measure before optimizing, and optimize hot spots only. Profilers like `perf` tell where time is spent._
+//!
+//! Get more info: [The Rust Performance Book](https://nnethercote.github.io/perf-book/) and [criterion](https://crates.io/crates/criterion) crate, a complete benchmark harness.
+
+#[cfg(test)]
+mod tests;
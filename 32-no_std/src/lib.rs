@@ -0,0 +1,90 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+#![cfg_attr(not(test), no_std)]
+
+//! 32 no_std
+//! ---------
+//!
+//! Welcome to thirty-second step of this Rust workshop.
+//!
+//! This step focuses on writing a library without standard library, as required by embedded systems, firmwares, kernels or WebAssembly modules.
+//!
+//! _Note: this crate itself is `no_std`, except when tested (see [Testing](#testing)). Code is written into `ring_buffer.rs` and `parser.rs` files; tests check it builds without `std`. Run `cargo test --features alloc` to include `alloc` parts._
+//!
+//! ## Three layers
+//!
+//! Standard library is actually split in three crates:
+//!
+//! * [`core`](https://doc.rust-lang.org/core/): no dependency at all. Primitive types, `Option`, `Result`, iterators, slices and `str` methods, `Cell`, `fmt`, ...
+//! * [`alloc`](https://doc.rust-lang.org/alloc/): requires a heap allocator. `Box`, `Vec`, `String`, `Rc`, `BTreeMap`, `format!`, ...
+//! * [`std`](https://doc.rust-lang.org/std/): requires an operating system. Files, network, threads, `HashMap` (its hasher needs random numbers), `println!`, ...
+//!
+//! `std` re-exports `core` and `alloc`: `std::option::Option` **is** `core::option::Option`. A `#![no_std]` crate only links `core`, and may opt into `alloc` with `extern crate alloc;`.
+//!
+//! ## What disappears
+//!
+//! Prelude of a `no_std` crate is [`core::prelude`](https://doc.rust-lang.org/core/prelude/index.html). `Option`, `Result`, `Iterator`, `Clone`, `Default`, ... are still there, as well as macros like `assert!`, `panic!`, `matches!` or `write!`. But `Box`, `Vec`, `String`, `ToString`, `vec!`, `format!` and `println!` aren't anymore:
+//!
+//! ```rust
+//! extern crate alloc;
+//!
+//! use alloc::string::{String, ToString};
+//! use alloc::vec::Vec;
+//!
+//! fn names(count: u32) -> Vec<String> {
+//!     (0..count).map(|index| index.to_string()).collect()
+//! }
+//! ```
+//!
+//! Without allocator, data lives on stack or in `static`s, with sizes known at compile time: fixed-capacity collections rely on const generics (see [28-type_level](../28_type_level/index.html)). Errors can't hold a `String` either: `Copy` enums with a `&'static str` message are typical.
+//!
+//! _Note: `core::fmt` works without allocation, but its machinery is quite large for small devices. That's why many embedded crates avoid `Display` and `write!` in hot paths._
+//!
+//! ## Features
+//!
+//! Cargo _features_ enable optional parts of a crate. They are declared into `Cargo.toml` and checked with `cfg` attribute:
+//!
+//! ```toml
+//! [features]
+//! alloc = []
+//! ```
+//!
+//! ```rust
+//! #[cfg(feature = "alloc")]
+//! extern crate alloc;
+//!
+//! #[cfg(feature = "alloc")]
+//! pub fn to_vec(bytes: &[u8]) -> alloc::vec::Vec<u8> {
+//!     bytes.to_vec()
+//! }
+//! ```
+//!
+//! A crate depending on this one enables it with `features = ["alloc"]`. Features must be _additive_: enabling one may only add items, never remove or change existing ones.
+//!
+//! ## Testing
+//!
+//! Test harness needs `std` (to print results, catch panics, ...). Tests are built for host machine, so crate can be `no_std` in every build but test ones, as this one is:
+//!
+//! ```rust,ignore
+//! #![cfg_attr(not(test), no_std)]
+//! ```
+//!
+//! But then, test build can't prove main build doesn't use `std`: it must also be built without `cfg(test)`, for each combination of features. Ideally for a target without any operating system, like `thumbv7em-none-eabihf` (`rustup target add` it, then `cargo build --target ...`).
+//!
+//! _Note: a `no_std` **binary** must also define what happens on panic, with a `#[panic_handler]` function. Libraries don't._
+//!
+//! Get more info: [The Embedded Rust Book](https://docs.rust-embedded.org/book/intro/no-std.html) and [heapless](https://crates.io/crates/heapless) crate.
+
+// Declared by `no_std` builds only, but paths of `parser.rs` start with it
+#[cfg(test)]
+extern crate core;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod ring_buffer;
+pub mod parser;
+
+#[cfg(test)]
+mod tests;
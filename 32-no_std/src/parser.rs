@@ -0,0 +1,62 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Command received by a device, one per line: `RESET`, `GET <register>` or `SET <register> <value>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Reset,
+    Get { register: u8 },
+    Set { register: u8, value: u16 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand,
+    MissingArgument,
+    TooManyArguments,
+    InvalidDigit,
+    Overflow,
+}
+
+impl ParseError {
+    /// Describes error without `core::fmt` machinery, which is costly for small devices.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            ParseError::Empty => "empty command",
+            ParseError::UnknownCommand => "unknown command",
+            ParseError::MissingArgument => "missing argument",
+            ParseError::TooManyArguments => "too many arguments",
+            ParseError::InvalidDigit => "invalid digit",
+            ParseError::Overflow => "number too large",
+        }
+    }
+}
+
+/// Parses a decimal number, or a hexadecimal one when prefixed by `0x`.
+pub fn parse_number(bytes: &[u8]) -> Result<u32, ParseError> {
+    Err(ParseError::InvalidDigit) //TODO: without `str::parse`, input may not be UTF-8
+}
+
+fn argument<'a, I: Iterator<Item = &'a [u8]>>(words: &mut I) -> Result<u32, ParseError> {
+    parse_number(words.next().ok_or(ParseError::MissingArgument)?)
+}
+
+/// Parses a single command. Words are separated by one or more spaces.
+pub fn parse_command(line: &[u8]) -> Result<Command, ParseError> {
+    Err(ParseError::Empty) //TODO
+}
+
+/// Parses each non-blank line of `input`. Lines may end with `\n` or `\r\n`.
+pub fn commands<'a>(input: &'a [u8]) -> impl Iterator<Item = Result<Command, ParseError>> + 'a {
+    input
+        .split(|&byte| byte == b'\n')
+        .map(parse_command) //TODO: handle `\r\n` and skip blank lines
+}
+
+/// Parses all commands of `input`, stopping at first error.
+#[cfg(feature = "alloc")]
+pub fn parse_all(input: &[u8]) -> Result<Vec<Command>, ParseError> {
+    Ok(Vec::new()) //TODO
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// First-in first-out queue of at most `N` items, stored inline (no heap allocation).
+#[derive(Debug)]
+pub struct RingBuffer<T, const N: usize> {
+    items: [Option<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new() -> Self {
+        RingBuffer { items: core::array::from_fn(|_| None), head: 0, len: 0 }
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds `item` at the end, or gives it back when buffer is full.
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        Err(item) //TODO
+    }
+
+    /// Adds `item` at the end. When buffer is full, oldest item is removed to make room, and returned.
+    pub fn push_overwrite(&mut self, item: T) -> Option<T> {
+        Some(item) //TODO: beware of zero capacity
+    }
+
+    /// Removes oldest item.
+    pub fn pop(&mut self) -> Option<T> {
+        None //TODO
+    }
+
+    /// Returns oldest item, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        None //TODO
+    }
+
+    /// Iterates from oldest to newest item.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter { buffer: self, index: 0 }
+    }
+
+    /// Copies items, from oldest to newest, into a `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        Vec::new() //TODO
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        RingBuffer::new()
+    }
+}
+
+pub struct Iter<'a, T: 'a, const N: usize> {
+    buffer: &'a RingBuffer<T, N>,
+    index: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        None //TODO
+    }
+}
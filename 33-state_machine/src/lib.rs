@@ -0,0 +1,105 @@
+#![doc(
+html_playground_url = "https://play.rust-lang.org/",
+)]
+
+//! 33 State machine
+//! ----------------
+//!
+//! Welcome to thirty-third step of this Rust workshop.
+//!
+//! This step focuses on _state machines_: a vending machine is first written with enums, then with the type-state pattern seen in [28-type_level](../28_type_level/index.html).
+//!
+//! _Note: some tests of this step check that illegal transitions do **not** compile. They call `rustc` on `vending.rs` and `type_state.rs` files, so they must stay self-contained._
+//!
+//! ## States, events and transitions
+//!
+//! A state machine is always in one _state_ among a finite set. It receives _events_, and each _transition_ tells which state comes next, given current state and event. Events not allowed in current state are rejected.
+//!
+//! | State                | Event                                  | Next state   |
+//! |----------------------|----------------------------------------|--------------|
+//! | `Idle`               | `Insert(coin)`                         | `Collecting` |
+//! | `Collecting`         | `Insert(coin)`                         | `Collecting` |
+//! | `Collecting`         | `Select(product)`, if credit is enough | `Dispensing` |
+//! | `Collecting`         | `Cancel`                               | `Refunding`  |
+//! | `Dispensing`         | `Take`                                 | `Idle`       |
+//! | `Refunding`          | `Take`                                 | `Idle`       |
+//! | any but `OutOfOrder` | `Fault`                                | `OutOfOrder` |
+//! | `OutOfOrder`         | `Repair`                               | `Idle`       |
+//!
+//! ## Enum state machine
+//!
+//! Unlike a single `match` on an event (see [07-pattern_matching](../07_pattern_matching/index.html)), next step depends on **both** state and event. Matching on a tuple lays out the _transition table_, one line per transition. Each state variant carries its own data, and guards express conditions:
+//!
+//! ```rust
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Door {
+//!     Opened,
+//!     Closed,
+//!     Locked { code: u32 },
+//! }
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! enum Action {
+//!     Open,
+//!     Close,
+//!     Lock(u32),
+//!     Unlock(u32),
+//! }
+//!
+//! fn transition(door: Door, action: Action) -> Option<Door> {
+//!     match (door, action) {
+//!         (Door::Closed, Action::Open) => Some(Door::Opened),
+//!         (Door::Opened, Action::Close) => Some(Door::Closed),
+//!         (Door::Closed, Action::Lock(code)) => Some(Door::Locked { code }),
+//!         (Door::Locked { code }, Action::Unlock(attempt)) if attempt == code => Some(Door::Closed),
+//!         _ => None, // Any other combination is rejected
+//!     }
+//! }
+//!
+//! assert_eq!(None, transition(Door::Locked { code: 1234 }, Action::Unlock(0)));
+//! ```
+//!
+//! Keep transition function _pure_ (no side effect, same result for same inputs): it's then trivial to test, and a sequence of events can be _replayed_ to rebuild a state, or to record all visited states (a _trace_).
+//!
+//! _Note: wildcard `_` arm is convenient, but compiler can't tell anymore when a new state or event is forgotten. Listing rejected combinations explicitly is safer for critical machines._
+//!
+//! ## Type-state machine
+//!
+//! With enums, illegal transitions are detected at runtime. With one **type** per state, they don't even compile: transitions are methods only available on some states, which consume `self` (see [04-ownership](../04_ownership/index.html)) so that a previous state can't be reused:
+//!
+//! ```rust
+//! struct Closed;
+//! struct Locked { code: u32 }
+//!
+//! struct Door<S> {
+//!     state: S,
+//! }
+//!
+//! impl Door<Closed> {
+//!     fn lock(self, code: u32) -> Door<Locked> {
+//!         Door { state: Locked { code } }
+//!     }
+//! }
+//!
+//! impl Door<Locked> {
+//!     /// Outcome is only known at runtime: wrong code gives locked door back
+//!     fn unlock(self, attempt: u32) -> Result<Door<Closed>, Door<Locked>> {
+//!         if attempt == self.state.code { Ok(Door { state: Closed }) } else { Err(self) }
+//!     }
+//! }
+//!
+//! let door = Door { state: Closed }.lock(1234);
+//! // door.lock(42) doesn't compile: a locked door can't be locked again
+//! let door = door.unlock(1234).ok().unwrap();
+//! ```
+//!
+//! A transition allowed from several states is implemented once, for all types implementing a _marker trait_: `impl<S: Operational> Machine<S>`.
+//!
+//! ## Which one ?
+//!
+//! Type-state machines catch errors earlier, and are great for APIs (builders, connections, protocols, ...). But type of a value must be known at compile time: a state read from a file or a network, several machines into a `Vec`, or events coming from users require an enum. Both can be combined, as type-state transitions may record enum _snapshots_.
+//!
+//! Get more info: [rust-book/oop-design-patterns](https://doc.rust-lang.org/book/ch18-03-oo-design-patterns.html) and [Pretty State Machine Patterns in Rust](https://hoverbear.org/blog/rust-state-machine-pattern/).
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+use super::vending::{Coin, Product, State};
+
+/// Waiting for a coin.
+pub struct Idle;
+
+/// Coins have been inserted.
+pub struct Collecting {
+    credit: u32,
+}
+
+/// Product (and change) is waiting to be taken.
+pub struct Dispensing {
+    product: Product,
+    change: u32,
+}
+
+/// Credit is waiting to be taken back.
+pub struct Refunding {
+    amount: u32,
+}
+
+/// Waiting for a technician.
+pub struct OutOfOrder;
+
+/// Gives runtime equivalent of a state, to be traced.
+pub trait Snapshot {
+    fn snapshot(&self) -> State;
+}
+
+impl Snapshot for Idle {
+    fn snapshot(&self) -> State {
+        State::Idle
+    }
+}
+
+impl Snapshot for Collecting {
+    fn snapshot(&self) -> State {
+        State::Collecting { credit: self.credit }
+    }
+}
+
+impl Snapshot for Dispensing {
+    fn snapshot(&self) -> State {
+        State::Dispensing { product: self.product, change: self.change }
+    }
+}
+
+impl Snapshot for Refunding {
+    fn snapshot(&self) -> State {
+        State::Refunding { amount: self.amount }
+    }
+}
+
+impl Snapshot for OutOfOrder {
+    fn snapshot(&self) -> State {
+        State::OutOfOrder
+    }
+}
+
+/// Marks states in which machine may break down.
+pub trait Operational: Snapshot {}
+
+impl Operational for Idle {}
+impl Operational for Collecting {}
+impl Operational for Dispensing {}
+impl Operational for Refunding {}
+
+/// Vending machine whose type tells its state: only allowed transitions are available.
+pub struct Machine<S> {
+    state: S,
+    trace: Vec<State>,
+}
+
+impl<S: Snapshot> Machine<S> {
+    /// Moves to `next` state, consuming current one.
+    fn into_state<T: Snapshot>(mut self, next: T) -> Machine<T> {
+        self.trace.push(next.snapshot());
+        Machine { state: next, trace: self.trace }
+    }
+
+    pub fn state(&self) -> State {
+        self.state.snapshot()
+    }
+
+    /// States visited so far, starting with initial one.
+    pub fn trace(&self) -> &[State] {
+        &self.trace
+    }
+}
+
+impl Machine<Idle> {
+    pub fn new() -> Self {
+        Machine { state: Idle, trace: vec![State::Idle] }
+    }
+}
+
+//TODO: implement `insert()`, `select()`, `cancel()`, `take()`, `fault()` and `repair()`, only for states allowing them (see `transition()` into `vending.rs`)
@@ -0,0 +1,102 @@
+/// Coins accepted by machine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coin {
+    Ten,
+    Twenty,
+    Fifty,
+    Euro,
+}
+
+impl Coin {
+    pub fn cents(self) -> u32 {
+        match self {
+            Coin::Ten => 10,
+            Coin::Twenty => 20,
+            Coin::Fifty => 50,
+            Coin::Euro => 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Product {
+    Water,
+    Chips,
+    Soda,
+}
+
+impl Product {
+    /// Price in cents.
+    pub fn price(self) -> u32 {
+        match self {
+            Product::Water => 80,
+            Product::Chips => 120,
+            Product::Soda => 150,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Idle,
+    Collecting { credit: u32 },
+    Dispensing { product: Product, change: u32 },
+    Refunding { amount: u32 },
+    OutOfOrder,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    Insert(Coin),
+    Select(Product),
+    Cancel,
+    Take,
+    Fault,
+    Repair,
+}
+
+/// Transition table: returns next state, or `None` when `event` isn't allowed in `state`.
+pub fn transition(state: State, event: Event) -> Option<State> {
+    None //TODO: one match arm per allowed transition
+}
+
+/// Event which isn't allowed in `state`.
+#[derive(Debug, PartialEq)]
+pub struct Rejected {
+    pub state: State,
+    pub event: Event,
+}
+
+/// Vending machine recording all states it went through.
+#[derive(Debug)]
+pub struct VendingMachine {
+    state: State,
+    trace: Vec<State>,
+}
+
+impl VendingMachine {
+    pub fn new() -> Self {
+        VendingMachine { state: State::Idle, trace: vec![State::Idle] }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// States visited so far, starting with initial one.
+    pub fn trace(&self) -> &[State] {
+        &self.trace
+    }
+
+    /// Moves to next state. A rejected event leaves machine unchanged.
+    pub fn handle(&mut self, event: Event) -> Result<State, Rejected> {
+        Err(Rejected { state: self.state, event }) //TODO
+    }
+}
+
+/// Replays `events` on a new machine. Returns its trace, and rejected events.
+pub fn replay(events: &[Event]) -> (Vec<State>, Vec<Rejected>) {
+    let mut machine = VendingMachine::new();
+    let rejected = events.iter().filter_map(|&event| machine.handle(event).err()).collect();
+    (machine.trace, rejected)
+}
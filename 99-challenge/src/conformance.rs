@@ -0,0 +1,171 @@
+//! Conformance suite any [`Platform`] can be plugged into. It produces a scorecard telling what is
+//! left to do.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use compiler;
+use platform::{self, Error, Executor, Platform, Rust};
+
+/// Verbs every platform is checked against.
+pub const VERBS: &[&str] = &["build", "run", "test", "fmt-check"];
+
+/// Expectations for a platform, written by its author.
+pub struct Spec {
+    pub platform: Box<dyn Platform>,
+    /// Fixture trees, as lists of files, which platform must detect.
+    pub positive: Vec<&'static [&'static str]>,
+    /// Fixture trees which platform must not detect.
+    pub negative: Vec<&'static [&'static str]>,
+    /// Expected command line for each supported verb. Other verbs of [`VERBS`] must be unsupported.
+    pub commands: Vec<(&'static str, &'static str)>,
+}
+
+/// Specs of all registered platforms. Learners add theirs here, then run
+/// `cargo test -p 99_challenge conformance -- --nocapture`.
+pub fn specs() -> Vec<Spec> {
+    vec![
+        Spec {
+            platform: Box::new(Rust),
+            positive: vec![&["Cargo.toml"], &["Cargo.toml", "src/main.rs"]],
+            negative: vec![&[], &["src/main.rs"], &["sub/Cargo.toml"]],
+            commands: vec![("build", "cargo build"), ("run", "cargo run"), ("test", "cargo test"), ("fmt-check", "cargo fmt -- --check")],
+        },
+    ]
+}
+
+pub struct Check {
+    pub name: String,
+    /// Why check failed.
+    pub outcome: Result<(), String>,
+}
+
+pub struct Scorecard {
+    pub platform: String,
+    pub checks: Vec<Check>,
+}
+
+impl Scorecard {
+    pub fn passed(&self) -> usize {
+        self.checks.iter().filter(|check| check.outcome.is_ok()).count()
+    }
+
+    pub fn success(&self) -> bool {
+        self.passed() == self.checks.len()
+    }
+}
+
+/// Score, then one line per check:
+///
+/// ```text
+/// rust: 1/2 checks passed
+///   ok      detects ["Cargo.toml"]
+///   FAILED  command for build: expected `cargo build`, got `cargo b`
+/// ```
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}: {}/{} checks passed", self.platform, self.passed(), self.checks.len())?;
+        for check in &self.checks {
+            match check.outcome {
+                Ok(()) => writeln!(f, "  ok      {}", check.name)?,
+                Err(ref reason) => writeln!(f, "  FAILED  {}: {}", check.name, reason)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates a fresh directory with empty `files`, removed when dropped. Also used by workspace tests.
+pub(crate) struct Fixture {
+    pub(crate) root: PathBuf,
+}
+
+impl Fixture {
+    pub(crate) fn new(files: &[&str]) -> io::Result<Self> {
+        let root = compiler::temp_path("please_conformance");
+        fs::create_dir_all(&root)?;
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap_or(&root))?;
+            fs::write(path, "")?;
+        }
+        Ok(Fixture { root })
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Executor behaving as if no program were installed.
+struct NoToolchain;
+
+impl Executor for NoToolchain {
+    fn execute(&self, _dir: &Path, command: &[String]) -> io::Result<bool> {
+        Err(io::Error::new(io::ErrorKind::NotFound, format!("{} not found", command[0])))
+    }
+}
+
+fn check_detection(platform: &dyn Platform, files: &[&str], expected: bool) -> Result<(), String> {
+    let fixture = Fixture::new(files).map_err(|error| format!("can't create fixture: {}", error))?;
+    match platform.detect(&fixture.root) {
+        detected if detected == expected => Ok(()),
+        true => Err(String::from("detected, but should not")),
+        false => Err(String::from("not detected")),
+    }
+}
+
+fn check_command(platform: &dyn Platform, verb: &str, expected: Option<&str>) -> Result<(), String> {
+    let actual = platform.command(verb).map(|command| command.join(" "));
+    match (expected, actual) {
+        (Some(expected), Some(ref actual)) if expected == actual => Ok(()),
+        (None, None) => Ok(()),
+        (Some(expected), Some(actual)) => Err(format!("expected `{}`, got `{}`", expected, actual)),
+        (Some(expected), None) => Err(format!("expected `{}`, got unsupported", expected)),
+        (None, Some(actual)) => Err(format!("expected unsupported, got `{}`", actual)),
+    }
+}
+
+fn check_toolchain(platform: &dyn Platform) -> Result<(), String> {
+    let toolchain = platform.toolchain();
+    match VERBS.iter().filter_map(|verb| platform.command(verb)).find(|command| command[0] != toolchain) {
+        Some(command) => Err(format!("`{}` doesn't run `{}`", command.join(" "), toolchain)),
+        None => Ok(()),
+    }
+}
+
+fn check_missing_toolchain(platform: &dyn Platform) -> Result<(), String> {
+    let expected = Error::MissingToolchain { program: platform.toolchain().to_string() };
+    for verb in VERBS.iter().filter(|verb| platform.command(verb).is_some()) {
+        match platform::execute(platform, &NoToolchain, Path::new("."), verb) {
+            Err(ref error) if *error == expected => {},
+            Err(error) => return Err(format!("{}: expected \"{}\", got \"{}\"", verb, expected, error)),
+            Ok(_) => return Err(format!("{}: succeeded without toolchain", verb)),
+        }
+    }
+    Ok(())
+}
+
+/// Runs all checks of `spec`.
+pub fn check(spec: &Spec) -> Scorecard {
+    let platform = &*spec.platform;
+    let mut checks = vec![];
+    for files in &spec.positive {
+        checks.push(Check { name: format!("detects {:?}", files), outcome: check_detection(platform, files, true) });
+    }
+    for files in &spec.negative {
+        checks.push(Check { name: format!("ignores {:?}", files), outcome: check_detection(platform, files, false) });
+    }
+    for verb in VERBS {
+        let expected = spec.commands.iter().find(|&&(name, _)| name == *verb).map(|&(_, command)| command);
+        checks.push(Check { name: format!("command for {}", verb), outcome: check_command(platform, verb, expected) });
+    }
+    checks.push(Check { name: String::from("commands run toolchain"), outcome: check_toolchain(platform) });
+    checks.push(Check { name: String::from("reports missing toolchain"), outcome: check_missing_toolchain(platform) });
+    Scorecard { platform: platform.name().to_string(), checks }
+}
+
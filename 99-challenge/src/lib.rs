@@ -22,3 +22,75 @@
 //! * Implement the `trait Platform` define in [src/platform/mod.rs](https://github.com/RustBeginners/please/blob/master/src/platform/mod.rs)
 //!
 //! * Get an example (rust) of a platform implementation here: [src/platform/rust.rs](https://github.com/RustBeginners/please/blob/master/src/platform/rust.rs)
+//!
+//! # Going further
+//!
+//! Once your platform is merged, here are some bigger contributions to `please`. They touch its core
+//! rather than a single platform, so discuss them with maintainers in an issue first.
+//!
+//! This crate holds a reference implementation of each one, written against [`platform::Platform`], a
+//! local mirror of `please` trait. Port them to `please` itself, or use them to check your design.
+//!
+//! ## Verb pipelines
+//!
+//! Some verbs depend on others: `please run` and `please test` both need a successful `please build`.
+//! Users may also want to declare their own pipelines, such as `ci = [fmt-check, build, test]`.
+//!
+//! * Resolve dependencies in the dispatcher (the code calling `Platform`), **not** in each platform,
+//!   so every language benefits from it
+//!
+//! * Run steps in order and stop at the first failure
+//!
+//! * Print a summary of each step with its status and duration (see [`std::time::Instant`](https://doc.rust-lang.org/std/time/struct.Instant.html))
+//!
+//! See [`pipeline::dispatch`], and `pipeline_should` tests.
+//!
+//! ## Multi-project workspaces
+//!
+//! This very workshop is a Cargo workspace with many members, and real monorepos often mix Rust
+//! crates with other technologies (e.g. a web frontend).
+//!
+//! * Discover sub-projects below the root directory, each one with its own detected platform
+//!
+//! * Run a verb across all of them (`please build --all`) or only a filtered subset
+//!
+//! * Aggregate failures instead of stopping at the first one, and report them all at the end
+//!
+//! * Optionally run independent projects in parallel (see [12-parallelism](../12_parallelism/index.html))
+//!
+//! See [`workspace::discover`] and [`workspace::run`], and `workspace_should` tests.
+//!
+//! ## Platform conformance suite
+//!
+//! To check a new platform, a shared test suite is written once and plugged into any `Platform`
+//! implementation. It checks:
+//!
+//! * detection against fixture trees, both positive (e.g. a directory with a `Cargo.toml` for
+//!   `rust`) and negative ones
+//!
+//! * command lines produced for every verb
+//!
+//! * error behaviour when toolchain is missing
+//!
+//! Then it prints a scorecard of what passes and what is left, so that contributors only have to
+//! register their platform and run a single command.
+//!
+//! Port your platform to [`platform`] module, add it to [`platform::platforms`] and its expectations
+//! to [`conformance::specs`], then run:
+//!
+//! ```text
+//! cargo test -p 99_challenge conformance -- --nocapture
+//! ```
+
+pub mod platform;
+pub mod pipeline;
+pub mod workspace;
+pub mod conformance;
+
+// Only `temp_path` is used, by conformance fixtures
+#[path = "../../common/compiler.rs"]
+#[allow(dead_code)]
+mod compiler;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+//! Verb pipelines, resolved by the dispatcher so that every platform benefits from them.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use platform::{self, Error, Executor, Platform};
+
+/// Verb dependencies and user-defined pipelines.
+pub struct Pipelines {
+    /// Verbs which must succeed before a verb, e.g. `build` before `test`.
+    dependencies: BTreeMap<String, Vec<String>>,
+    /// Named sequences of steps, e.g. `ci = [fmt-check, build, test]`.
+    pipelines: BTreeMap<String, Vec<String>>,
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// Built-in dependencies only: `run` and `test` both need `build`.
+impl Default for Pipelines {
+    fn default() -> Self {
+        let mut dependencies = BTreeMap::new();
+        dependencies.insert(String::from("run"), names(&["build"]));
+        dependencies.insert(String::from("test"), names(&["build"]));
+        Pipelines { dependencies, pipelines: BTreeMap::new() }
+    }
+}
+
+impl Pipelines {
+    /// Built-in dependencies only, same as [`Pipelines::default`].
+    pub fn new() -> Self {
+        Pipelines::default()
+    }
+
+    /// Adds built-in dependencies to pipelines defined in `definitions`, one per line:
+    ///
+    /// ```text
+    /// # Comments and blank lines are ignored
+    /// ci = [fmt-check, build, test]
+    /// ```
+    pub fn parse(definitions: &str) -> Result<Self, Error> {
+        let mut pipelines = Pipelines::new();
+        for (index, line) in definitions.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, steps) = parse_definition(line).ok_or(Error::Definition(index + 1))?;
+            pipelines.define(name, &steps);
+        }
+        Ok(pipelines)
+    }
+
+    pub fn define(&mut self, name: &str, steps: &[&str]) {
+        self.pipelines.insert(name.to_string(), names(steps));
+    }
+
+    /// Verbs to run for `verb`, in order, each one at most once.
+    pub fn plan(&self, verb: &str) -> Result<Vec<String>, Error> {
+        let mut plan = vec![];
+        self.expand(verb, &mut vec![], &mut plan)?;
+        Ok(plan)
+    }
+
+    fn expand(&self, verb: &str, stack: &mut Vec<String>, plan: &mut Vec<String>) -> Result<(), Error> {
+        if plan.iter().any(|planned| planned == verb) {
+            return Ok(());
+        }
+        if stack.iter().any(|expanding| expanding == verb) {
+            let mut cycle = stack.clone();
+            cycle.push(verb.to_string());
+            return Err(Error::Cycle(cycle));
+        }
+        stack.push(verb.to_string());
+        if let Some(steps) = self.pipelines.get(verb) {
+            for step in steps {
+                self.expand(step, stack, plan)?;
+            }
+        } else {
+            for dependency in self.dependencies.get(verb).into_iter().flatten() {
+                self.expand(dependency, stack, plan)?;
+            }
+            plan.push(verb.to_string());
+        }
+        stack.pop();
+        Ok(())
+    }
+}
+
+/// Parses `name = [step, ...]`.
+fn parse_definition(line: &str) -> Option<(&str, Vec<&str>)> {
+    let mut parts = line.splitn(2, '=');
+    let name = parts.next()?.trim();
+    let steps = parts.next()?.trim();
+    if name.is_empty() || !steps.starts_with('[') || !steps.ends_with(']') {
+        return None;
+    }
+    let steps: Vec<&str> = steps[1..steps.len() - 1].split(',').map(str::trim).collect();
+    if steps.iter().any(|step| step.is_empty()) {
+        return None;
+    }
+    Some((name, steps))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Passed,
+    Failed,
+    /// Not run, because a previous step failed.
+    Skipped,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    pub verb: String,
+    pub status: Status,
+    pub duration: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub steps: Vec<Step>,
+}
+
+impl Summary {
+    pub fn success(&self) -> bool {
+        self.steps.iter().all(|step| step.status == Status::Passed)
+    }
+}
+
+/// One line per step, with its status and duration:
+///
+/// ```text
+/// build      passed   1.25s
+/// test       failed   0.40s
+/// run        skipped
+/// ```
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for step in &self.steps {
+            match step.status {
+                Status::Passed => writeln!(f, "{:<10} passed  {:>6.2}s", step.verb, step.duration.as_secs_f64())?,
+                Status::Failed => writeln!(f, "{:<10} failed  {:>6.2}s", step.verb, step.duration.as_secs_f64())?,
+                Status::Skipped => writeln!(f, "{:<10} skipped", step.verb)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs all steps planned for `verb` in `dir`, and stops at first failure.
+/// Every step is checked to be supported before any is run.
+pub fn dispatch(pipelines: &Pipelines, platform: &dyn Platform, executor: &dyn Executor, dir: &Path, verb: &str) -> Result<Summary, Error> {
+    let plan = pipelines.plan(verb)?;
+    if let Some(unsupported) = plan.iter().find(|step| platform.command(step).is_none()) {
+        return Err(Error::Unsupported { platform: platform.name().to_string(), verb: unsupported.clone() });
+    }
+
+    let mut steps = vec![];
+    let mut failed = false;
+    for verb in plan {
+        if failed {
+            steps.push(Step { verb, status: Status::Skipped, duration: Duration::from_secs(0) });
+            continue;
+        }
+        let start = Instant::now();
+        let passed = platform::execute(platform, executor, dir, &verb)?;
+        failed = !passed;
+        let status = if passed { Status::Passed } else { Status::Failed };
+        steps.push(Step { verb, status, duration: start.elapsed() });
+    }
+    Ok(Summary { steps })
+}
@@ -0,0 +1,126 @@
+//! Local mirror of `please` platforms, so that the dispatcher and the conformance suite can be
+//! written and tested in this workshop.
+
+use std::fmt;
+use std::io;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A technology `please` knows how to drive, such as `rust`. Shared between threads when projects run in parallel.
+pub trait Platform: Sync {
+    /// Short name, displayed in reports.
+    fn name(&self) -> &str;
+
+    /// Program which must be installed to run any command, e.g. `cargo`.
+    fn toolchain(&self) -> &str;
+
+    /// Tells whether project in `dir` is built with this platform.
+    fn detect(&self, dir: &Path) -> bool;
+
+    /// Command line for `verb`, starting with program, or `None` when verb isn't supported.
+    fn command(&self, verb: &str) -> Option<Vec<String>>;
+}
+
+/// Reference platform, for Cargo projects.
+pub struct Rust;
+
+impl Platform for Rust {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn toolchain(&self) -> &str {
+        "cargo"
+    }
+
+    fn detect(&self, dir: &Path) -> bool {
+        dir.join("Cargo.toml").is_file()
+    }
+
+    fn command(&self, verb: &str) -> Option<Vec<String>> {
+        let args: &[&str] = match verb {
+            "build" => &["build"],
+            "run" => &["run"],
+            "test" => &["test"],
+            "fmt-check" => &["fmt", "--", "--check"],
+            _ => return None,
+        };
+        Some(iter::once("cargo").chain(args.iter().cloned()).map(String::from).collect())
+    }
+}
+
+/// Runs command lines. Tests use fake executors, so that no toolchain is needed.
+pub trait Executor: Sync {
+    /// Runs `command` in `dir`, and tells whether it succeeded.
+    /// Fails with `io::ErrorKind::NotFound` when program isn't installed.
+    fn execute(&self, dir: &Path, command: &[String]) -> io::Result<bool>;
+}
+
+/// Executor spawning real processes.
+pub struct System;
+
+impl Executor for System {
+    fn execute(&self, dir: &Path, command: &[String]) -> io::Result<bool> {
+        let status = Command::new(&command[0]).args(&command[1..]).current_dir(dir).status()?;
+        Ok(status.success())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Line of pipeline definitions which can't be parsed.
+    Definition(usize),
+    /// Pipeline includes itself, directly or not.
+    Cycle(Vec<String>),
+    /// No registered platform detects project in directory.
+    NoPlatform(PathBuf),
+    Unsupported { platform: String, verb: String },
+    MissingToolchain { program: String },
+    /// Command couldn't be started for another reason.
+    Spawn { program: String, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Definition(line) => write!(f, "invalid pipeline definition at line {}", line),
+            Error::Cycle(ref path) => write!(f, "pipeline cycle: {}", path.join(" -> ")),
+            Error::NoPlatform(ref dir) => write!(f, "no platform detected in '{}'", dir.display()),
+            Error::Unsupported { ref platform, ref verb } => write!(f, "platform '{}' doesn't support '{}'", platform, verb),
+            Error::MissingToolchain { ref program } => write!(f, "'{}' is not installed", program),
+            Error::Spawn { ref program, ref message } => write!(f, "can't start '{}': {}", program, message),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+/// Runs a single `verb` of `platform` in `dir`, and tells whether it succeeded.
+pub fn execute(platform: &dyn Platform, executor: &dyn Executor, dir: &Path, verb: &str) -> Result<bool, Error> {
+    let command = platform.command(verb).ok_or_else(|| Error::Unsupported {
+        platform: platform.name().to_string(),
+        verb: verb.to_string(),
+    })?;
+    executor.execute(dir, &command).map_err(|error| {
+        let program = command[0].clone();
+        if error.kind() == io::ErrorKind::NotFound {
+            Error::MissingToolchain { program }
+        } else {
+            Error::Spawn { program, message: error.to_string() }
+        }
+    })
+}
+
+/// Returns first platform detecting project in `dir`.
+pub fn detect<'a>(platforms: &'a [Box<dyn Platform>], dir: &Path) -> Result<&'a dyn Platform, Error> {
+    platforms.iter()
+        .find(|platform| platform.detect(dir))
+        .map(|platform| &**platform)
+        .ok_or_else(|| Error::NoPlatform(dir.to_path_buf()))
+}
+
+/// Platforms known by `please`. Learners add theirs here.
+pub fn platforms() -> Vec<Box<dyn Platform>> {
+    vec![Box::new(Rust)]
+}
@@ -0,0 +1,354 @@
+use std::io;
+use std::iter;
+use std::path::Path;
+use std::sync::Mutex;
+
+use platform::{Executor, Platform};
+
+/// Executor recording command lines instead of running them.
+struct Fake {
+    /// Command lines ran so far, joined with spaces.
+    executed: Mutex<Vec<String>>,
+    /// Command lines which fail.
+    failing: Vec<&'static str>,
+    /// Whether toolchain is installed.
+    installed: bool,
+}
+
+impl Fake {
+    fn new() -> Self {
+        Fake { executed: Mutex::new(vec![]), failing: vec![], installed: true }
+    }
+
+    fn failing(failing: &[&'static str]) -> Self {
+        Fake { failing: failing.to_vec(), ..Fake::new() }
+    }
+
+    fn executed(&self) -> Vec<String> {
+        self.executed.lock().unwrap().clone()
+    }
+}
+
+impl Executor for Fake {
+    fn execute(&self, _dir: &Path, command: &[String]) -> io::Result<bool> {
+        if !self.installed {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no such program"));
+        }
+        let line = command.join(" ");
+        let success = !self.failing.contains(&line.as_str());
+        self.executed.lock().unwrap().push(line);
+        Ok(success)
+    }
+}
+
+/// Web frontend platform, to mix technologies in a workspace.
+struct Node;
+
+impl Platform for Node {
+    fn name(&self) -> &str {
+        "node"
+    }
+
+    fn toolchain(&self) -> &str {
+        "npm"
+    }
+
+    fn detect(&self, dir: &Path) -> bool {
+        dir.join("package.json").is_file()
+    }
+
+    fn command(&self, verb: &str) -> Option<Vec<String>> {
+        let args: &[&str] = match verb {
+            "build" => &["run", "build"],
+            "test" => &["test"],
+            "run" => &["start"],
+            _ => return None,
+        };
+        Some(iter::once("npm").chain(args.iter().cloned()).map(String::from).collect())
+    }
+}
+
+mod rust_should {
+    use std::path::Path;
+
+    use platform::{self, Error, Platform, Rust};
+    use super::Fake;
+
+    #[test]
+    fn build_commands_with_cargo() {
+        assert_eq!(Some(vec![String::from("cargo"), String::from("build")]), Rust.command("build"));
+        assert_eq!(Some(vec![String::from("cargo"), String::from("fmt"), String::from("--"), String::from("--check")]), Rust.command("fmt-check"));
+        assert_eq!(None, Rust.command("deploy"));
+    }
+
+    #[test]
+    fn detect_cargo_manifest() {
+        assert!(Rust.detect(Path::new(env!("CARGO_MANIFEST_DIR"))));
+        assert!(!Rust.detect(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src")));
+    }
+
+    #[test]
+    fn report_missing_toolchain() {
+        let executor = Fake { installed: false, ..Fake::new() };
+        assert_eq!(Err(Error::MissingToolchain { program: String::from("cargo") }), platform::execute(&Rust, &executor, Path::new("."), "build"));
+    }
+}
+
+mod pipeline_should {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use pipeline::{self, Pipelines, Status, Step, Summary};
+    use platform::{Error, Rust};
+    use super::Fake;
+
+    fn plan(pipelines: &Pipelines, verb: &str) -> Vec<String> {
+        pipelines.plan(verb).unwrap()
+    }
+
+    #[test]
+    fn build_before_run_and_test() {
+        let pipelines = Pipelines::new();
+        assert_eq!(vec!["build"], plan(&pipelines, "build"));
+        assert_eq!(vec!["build", "run"], plan(&pipelines, "run"));
+        assert_eq!(vec!["build", "test"], plan(&pipelines, "test"));
+        assert_eq!(vec!["fmt-check"], plan(&pipelines, "fmt-check"));
+    }
+
+    #[test]
+    fn expand_user_defined_pipelines_once_per_verb() {
+        let pipelines = Pipelines::parse("# checks run by CI\nci = [fmt-check, build, test]\n\nall = [ci, run]\n").unwrap();
+        assert_eq!(vec!["fmt-check", "build", "test"], plan(&pipelines, "ci"));
+        assert_eq!(vec!["fmt-check", "build", "test", "run"], plan(&pipelines, "all"));
+    }
+
+    #[test]
+    fn reject_invalid_definitions() {
+        assert_eq!(Some(Error::Definition(2)), Pipelines::parse("ci = [build]\nci [build]").err());
+        assert_eq!(Some(Error::Definition(1)), Pipelines::parse("ci = build, test").err());
+        assert_eq!(Some(Error::Definition(1)), Pipelines::parse("ci = [build, , test]").err());
+        assert_eq!(Some(Error::Definition(1)), Pipelines::parse(" = [build]").err());
+    }
+
+    #[test]
+    fn detect_cycles() {
+        let pipelines = Pipelines::parse("a = [build, b]\nb = [c]\nc = [a]").unwrap();
+        assert_eq!(Err(Error::Cycle(vec![String::from("a"), String::from("b"), String::from("c"), String::from("a")])), pipelines.plan("a"));
+    }
+
+    #[test]
+    fn run_steps_in_order() {
+        let executor = Fake::new();
+        let pipelines = Pipelines::parse("ci = [fmt-check, build, test]").unwrap();
+        let summary = pipeline::dispatch(&pipelines, &Rust, &executor, Path::new("."), "ci").unwrap();
+        assert!(summary.success());
+        assert_eq!(vec!["cargo fmt -- --check", "cargo build", "cargo test"], executor.executed());
+    }
+
+    #[test]
+    fn stop_at_first_failure() {
+        let executor = Fake::failing(&["cargo build"]);
+        let summary = pipeline::dispatch(&Pipelines::new(), &Rust, &executor, Path::new("."), "test").unwrap();
+        assert!(!summary.success());
+        let statuses: Vec<Status> = summary.steps.iter().map(|step| step.status).collect();
+        assert_eq!(vec![Status::Failed, Status::Skipped], statuses);
+        assert_eq!(vec!["cargo build"], executor.executed());
+    }
+
+    #[test]
+    fn check_all_steps_are_supported_before_running() {
+        let executor = Fake::new();
+        let pipelines = Pipelines::parse("release = [build, publish]").unwrap();
+        assert_eq!(
+            Err(Error::Unsupported { platform: String::from("rust"), verb: String::from("publish") }),
+            pipeline::dispatch(&pipelines, &Rust, &executor, Path::new("."), "release")
+        );
+        assert!(executor.executed().is_empty());
+    }
+
+    #[test]
+    fn print_summary() {
+        let summary = Summary {
+            steps: vec![
+                Step { verb: String::from("build"), status: Status::Passed, duration: Duration::from_millis(1250) },
+                Step { verb: String::from("test"), status: Status::Failed, duration: Duration::from_millis(400) },
+                Step { verb: String::from("run"), status: Status::Skipped, duration: Duration::from_secs(0) },
+            ],
+        };
+        assert_eq!("build      passed    1.25s\ntest       failed    0.40s\nrun        skipped\n", summary.to_string());
+    }
+}
+
+mod workspace_should {
+    use std::path::{Path, PathBuf};
+
+    use conformance::Fixture;
+    use pipeline::Pipelines;
+    use platform::{Error, Platform, Rust};
+    use workspace::{self, Selection};
+    use super::{Fake, Node};
+
+    const MONOREPO: &[&str] = &[
+        "Cargo.toml",
+        "api/Cargo.toml",
+        "api/target/debug/Cargo.toml",
+        "cli/Cargo.toml",
+        "docs/README.md",
+        "web/package.json",
+        "web/node_modules/left-pad/package.json",
+        ".git/Cargo.toml",
+    ];
+
+    fn platforms() -> Vec<Box<dyn Platform>> {
+        vec![Box::new(Rust), Box::new(Node)]
+    }
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn discover_projects_with_their_platform() {
+        let fixture = Fixture::new(MONOREPO).unwrap();
+        let root = &fixture.root;
+        let platforms = platforms();
+        let projects = workspace::discover(root, &platforms).unwrap();
+        let found: Vec<(PathBuf, &str)> = projects.iter().map(|project| (project.path.clone(), project.platform.name())).collect();
+        assert_eq!(
+            vec![(PathBuf::new(), "rust"), (PathBuf::from("api"), "rust"), (PathBuf::from("cli"), "rust"), (PathBuf::from("web"), "node")],
+            found
+        );
+    }
+
+    #[test]
+    fn select_projects_by_path_or_platform() {
+        let fixture = Fixture::new(MONOREPO).unwrap();
+        let root = &fixture.root;
+        let platforms = platforms();
+        let projects = workspace::discover(root, &platforms).unwrap();
+        let selected = |selection: Selection| -> Vec<PathBuf> {
+            workspace::select(&projects, &selection).iter().map(|project| project.path.clone()).collect()
+        };
+        assert_eq!(4, selected(Selection::All).len());
+        assert_eq!(paths(&["api", "web"]), selected(Selection::Only(vec![String::from("api"), String::from("node")])));
+        assert!(selected(Selection::Only(vec![String::from("ap")])).is_empty());
+    }
+
+    #[test]
+    fn run_verb_across_all_projects_and_aggregate_failures() {
+        let fixture = Fixture::new(&["api/Cargo.toml", "web/package.json", "worker/Cargo.toml"]).unwrap();
+        let root = &fixture.root;
+        let platforms = platforms();
+        let projects = workspace::discover(root, &platforms).unwrap();
+        let executor = Fake::failing(&["npm test"]);
+        let report = workspace::run(&Pipelines::new(), &executor, root, &workspace::select(&projects, &Selection::All), "test", false);
+
+        assert!(!report.success());
+        assert_eq!(vec![Path::new("web")], report.failures());
+        assert_eq!(vec!["cargo build", "cargo test", "npm run build", "npm test", "cargo build", "cargo test"], executor.executed());
+        assert_eq!("api        ok\nweb        failed at test\nworker     ok\n1 of 3 projects failed", report.to_string());
+    }
+
+    #[test]
+    fn report_projects_which_can_not_run() {
+        let fixture = Fixture::new(&["api/Cargo.toml", "web/package.json"]).unwrap();
+        let root = &fixture.root;
+        let platforms = platforms();
+        let projects = workspace::discover(root, &platforms).unwrap();
+        let report = workspace::run(&Pipelines::new(), &Fake::new(), root, &workspace::select(&projects, &Selection::All), "fmt-check", false);
+
+        assert_eq!(vec![Path::new("web")], report.failures());
+        assert_eq!(Some(&Err(Error::Unsupported { platform: String::from("node"), verb: String::from("fmt-check") })), report.results.get(1).map(|result| &result.1));
+    }
+
+    #[test]
+    fn give_same_report_when_run_in_parallel() {
+        let fixture = Fixture::new(MONOREPO).unwrap();
+        let root = &fixture.root;
+        let platforms = platforms();
+        let projects = workspace::discover(root, &platforms).unwrap();
+        let selected = workspace::select(&projects, &Selection::All);
+        let sequential = workspace::run(&Pipelines::new(), &Fake::failing(&["npm run build"]), root, &selected, "run", false);
+        let executor = Fake::failing(&["npm run build"]);
+        let parallel = workspace::run(&Pipelines::new(), &executor, root, &selected, "run", true);
+
+        assert_eq!(sequential.to_string(), parallel.to_string());
+        assert_eq!(vec![Path::new("web")], parallel.failures());
+        let mut executed = executor.executed();
+        executed.sort();
+        assert_eq!(vec!["cargo build", "cargo build", "cargo build", "cargo run", "cargo run", "cargo run", "npm run build"], executed);
+    }
+}
+
+mod conformance_should {
+    use std::path::Path;
+
+    use conformance::{self, Spec};
+    use platform::{self, Platform};
+
+    /// Platform getting almost everything wrong.
+    struct Sloppy;
+
+    impl Platform for Sloppy {
+        fn name(&self) -> &str {
+            "sloppy"
+        }
+
+        fn toolchain(&self) -> &str {
+            "make"
+        }
+
+        fn detect(&self, _dir: &Path) -> bool {
+            true
+        }
+
+        fn command(&self, verb: &str) -> Option<Vec<String>> {
+            match verb {
+                "build" => Some(vec![String::from("make")]),
+                "test" => Some(vec![String::from("./run-tests.sh")]),
+                _ => None,
+            }
+        }
+    }
+
+    /// The one command learners run to see what is left.
+    #[test]
+    fn pass_for_every_registered_platform() {
+        let specs = conformance::specs();
+        let mut failures = vec![];
+        for platform in platform::platforms() {
+            let spec = specs.iter().find(|spec| spec.platform.name() == platform.name())
+                .unwrap_or_else(|| panic!("no conformance spec for platform '{}'", platform.name()));
+            let scorecard = conformance::check(spec);
+            println!("{}", scorecard);
+            if !scorecard.success() {
+                failures.push(scorecard.platform);
+            }
+        }
+        assert!(failures.is_empty(), "platforms with failed checks: {:?}", failures);
+    }
+
+    #[test]
+    fn score_failed_checks() {
+        let spec = Spec {
+            platform: Box::new(Sloppy),
+            positive: vec![&["Makefile"]],
+            negative: vec![&["README.md"]],
+            commands: vec![("build", "make all"), ("run", "make run"), ("test", "make test")],
+        };
+        let scorecard = conformance::check(&spec);
+        assert_eq!(2, scorecard.passed());
+        assert_eq!(
+            "sloppy: 2/8 checks passed\n\
+             \x20 ok      detects [\"Makefile\"]\n\
+             \x20 FAILED  ignores [\"README.md\"]: detected, but should not\n\
+             \x20 FAILED  command for build: expected `make all`, got `make`\n\
+             \x20 FAILED  command for run: expected `make run`, got unsupported\n\
+             \x20 FAILED  command for test: expected `make test`, got `./run-tests.sh`\n\
+             \x20 ok      command for fmt-check\n\
+             \x20 FAILED  commands run toolchain: `./run-tests.sh` doesn't run `make`\n\
+             \x20 FAILED  reports missing toolchain: test: expected \"'make' is not installed\", got \"'./run-tests.sh' is not installed\"\n",
+            scorecard.to_string()
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Multi-project workspaces: sub-projects are discovered below a root directory, each one with its
+//! own platform, and a verb runs across all of them.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use pipeline::{self, Pipelines, Status, Summary};
+use platform::{Error, Executor, Platform};
+
+/// Directories never containing sub-projects: build outputs and dependencies.
+const IGNORED: &[&str] = &["target", "node_modules"];
+
+pub struct Project<'a> {
+    /// Path relative to workspace root, empty for root itself.
+    pub path: PathBuf,
+    pub platform: &'a dyn Platform,
+}
+
+/// Finds all directories below `root` (included) detected by one of `platforms`, sorted by path.
+/// Hidden directories and build outputs are skipped.
+pub fn discover<'a>(root: &Path, platforms: &'a [Box<dyn Platform>]) -> io::Result<Vec<Project<'a>>> {
+    let mut projects = vec![];
+    visit(root, Path::new(""), platforms, &mut projects)?;
+    Ok(projects)
+}
+
+fn visit<'a>(root: &Path, relative: &Path, platforms: &'a [Box<dyn Platform>], projects: &mut Vec<Project<'a>>) -> io::Result<()> {
+    let dir = root.join(relative);
+    if let Some(platform) = platforms.iter().find(|platform| platform.detect(&dir)) {
+        projects.push(Project { path: relative.to_path_buf(), platform: &**platform });
+    }
+    let mut children = vec![];
+    for entry in fs::read_dir(&dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type()?.is_dir() && !name.starts_with('.') && !IGNORED.contains(&name.as_str()) {
+            children.push(name);
+        }
+    }
+    children.sort();
+    for child in children {
+        visit(root, &relative.join(child), platforms, projects)?;
+    }
+    Ok(())
+}
+
+/// Projects a verb runs on: `--all`, or only some of them.
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    /// Projects whose path starts with, or whose platform is, one of these names.
+    Only(Vec<String>),
+}
+
+impl Selection {
+    fn contains(&self, project: &Project) -> bool {
+        match *self {
+            Selection::All => true,
+            Selection::Only(ref names) => names.iter().any(|name| {
+                project.path.starts_with(name) || project.platform.name() == name
+            }),
+        }
+    }
+}
+
+pub fn select<'p, 'a>(projects: &'p [Project<'a>], selection: &Selection) -> Vec<&'p Project<'a>> {
+    projects.iter().filter(|project| selection.contains(project)).collect()
+}
+
+/// Outcome of a verb for each project, in projects order.
+pub struct Report {
+    pub results: Vec<(PathBuf, Result<Summary, Error>)>,
+}
+
+impl Report {
+    pub fn success(&self) -> bool {
+        self.failures().is_empty()
+    }
+
+    /// Projects which couldn't run, or whose pipeline failed.
+    pub fn failures(&self) -> Vec<&Path> {
+        self.results.iter()
+            .filter(|(_, result)| result.as_ref().map(|summary| !summary.success()).unwrap_or(true))
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+}
+
+fn display(path: &Path) -> String {
+    if path.as_os_str().is_empty() { String::from(".") } else { path.display().to_string() }
+}
+
+/// One line per project, then failures count:
+///
+/// ```text
+/// api        ok
+/// web        failed at test
+/// 1 of 2 projects failed
+/// ```
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (path, result) in &self.results {
+            let path = display(path);
+            match result {
+                Ok(summary) => match summary.steps.iter().find(|step| step.status == Status::Failed) {
+                    None => writeln!(f, "{:<10} ok", path)?,
+                    Some(step) => writeln!(f, "{:<10} failed at {}", path, step.verb)?,
+                },
+                Err(error) => writeln!(f, "{:<10} error: {}", path, error)?,
+            }
+        }
+        write!(f, "{} of {} projects failed", self.failures().len(), self.results.len())
+    }
+}
+
+/// Runs `verb` on every project in `root`. A failing project doesn't stop others.
+/// With `parallel`, each project runs in its own thread.
+pub fn run(pipelines: &Pipelines, executor: &dyn Executor, root: &Path, projects: &[&Project], verb: &str, parallel: bool) -> Report {
+    let run_one = &|project: &Project| pipeline::dispatch(pipelines, project.platform, executor, &root.join(&project.path), verb);
+    let outcomes: Vec<Result<Summary, Error>> = if parallel {
+        thread::scope(|scope| {
+            let handles: Vec<_> = projects.iter().map(|&project| scope.spawn(move || run_one(project))).collect();
+            handles.into_iter().map(|handle| handle.join().expect("project thread panicked")).collect()
+        })
+    } else {
+        projects.iter().map(|&project| run_one(project)).collect()
+    };
+    Report { results: projects.iter().map(|project| project.path.clone()).zip(outcomes).collect() }
+}
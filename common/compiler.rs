@@ -0,0 +1,85 @@
+//! Runs `rustc` on generated sources, for tests checking that some code is rejected by compiler for
+//! the expected reason.
+//!
+//! Chapters include this file as a module, with `#[path = "../../common/compiler.rs"] mod compiler;`.
+//! Each negative test should also compile a valid variant of its snippet, so that it can't pass
+//! before the code under test is written.
+//!
+//! Tests creating files also use [`temp_path`] from it.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Outcome of a compilation.
+pub struct Compilation {
+    pub success: bool,
+    /// Compiler messages.
+    pub stderr: String,
+}
+
+impl Compilation {
+    /// Panics with compiler messages unless compilation succeeded.
+    pub fn assert_success(&self) {
+        assert!(self.success, "compilation should succeed, but failed with:\n{}", self.stderr);
+    }
+
+    /// Panics unless compilation failed with `expected` in its messages, such as an error code
+    /// (`E0599`) or a part of a message.
+    pub fn assert_error(&self, expected: &str) {
+        assert!(!self.success, "compilation should fail with `{}`, but succeeded", expected);
+        assert!(self.stderr.contains(expected), "compilation should fail with `{}`, but failed with:\n{}", expected, self.stderr);
+    }
+}
+
+/// `rustc` of toolchain running tests: `RUSTC` environment variable if any, else the one next to
+/// `cargo` which built them, else the first one on `PATH`.
+fn rustc() -> PathBuf {
+    if let Some(rustc) = env::var_os("RUSTC") {
+        return PathBuf::from(rustc);
+    }
+    if let Some(cargo) = option_env!("CARGO") {
+        let rustc = Path::new(cargo).with_file_name(format!("rustc{}", env::consts::EXE_SUFFIX));
+        if rustc.is_file() {
+            return rustc;
+        }
+    }
+    PathBuf::from("rustc")
+}
+
+/// Path in system temporary directory starting with `prefix`, unique to each call and to each
+/// process, so that tests running in parallel don't share files.
+pub fn temp_path(prefix: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::SeqCst);
+    env::temp_dir().join(format!("{}_{}_{}", prefix, process::id(), unique))
+}
+
+/// Checks `source` with extra `rustc` arguments, in a fresh temporary directory. Only metadata is
+/// emitted, so that nothing is linked.
+pub fn compile(name: &str, source: &str, args: &[&str]) -> Compilation {
+    let dir = temp_path(&format!("workshop_compiler_{}", name));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("main.rs");
+    fs::write(&path, source).unwrap();
+
+    let output = Command::new(rustc())
+        .arg("--emit=metadata")
+        .arg("--out-dir").arg(&dir)
+        .args(args)
+        .arg(&path)
+        .output()
+        .expect("rustc must be available");
+    fs::remove_dir_all(&dir).unwrap();
+    Compilation {
+        success: output.status.success(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    }
+}
+
+/// Program made of `items`, and of `body` as `main` body. Unused code is allowed.
+pub fn program(items: &str, body: &str) -> String {
+    format!("#![allow(dead_code, unused)]\n{}\nfn main() {{\n{}\n}}\n", items, body)
+}
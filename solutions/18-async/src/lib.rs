@@ -0,0 +1,6 @@
+//! Solution of `18-async`, kept apart from other solutions as it needs Rust 2018 edition too.
+
+#[cfg(test)]
+mod test {
+	mod _18_async;
+}
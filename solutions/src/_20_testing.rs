@@ -0,0 +1,40 @@
+//! Solution of `20-testing/src/lib.rs`. It's outside of `test` module, so that its documentation tests
+//! run, and so that `tests/_20_testing.rs` integration tests can use it.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Returns whether `year` is a leap year in Gregorian calendar: every 4 years, except centuries which are not divisible by 400.
+///
+/// ```
+/// # use solutions::_20_testing::is_leap_year;
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// ```
+pub fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Returns `values` sorted in ascending order.
+///
+/// ```
+/// # use solutions::_20_testing::sort;
+/// assert_eq!(vec![1, 2, 3], sort(vec![3, 1, 2]));
+/// ```
+pub fn sort(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort();
+    values
+}
+
+/// Returns number of lines of file at `path`. Last line may or may not end with a newline.
+///
+/// ```no_run
+/// # use solutions::_20_testing::line_count;
+/// use std::path::Path;
+/// let count = line_count(Path::new("Cargo.toml")).unwrap();
+/// ```
+pub fn line_count(path: &Path) -> io::Result<usize> {
+    Ok(fs::read_to_string(path)?.lines().count())
+}
@@ -0,0 +1,113 @@
+//! Solution of `25-cli/src/lib.rs`. It's outside of `test` module, so that `count_solution` binary
+//! (see `src/bin/count_solution.rs`) and `tests/_25_cli.rs` integration tests can use it.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+
+pub const USAGE: &str = "\
+Usage: count [OPTIONS] [FILE]
+
+Counts lines, words and bytes of FILE, or of standard input when FILE is missing.
+
+Options:
+  -l, --lines    Print line count
+  -w, --words    Print word count
+  -c, --bytes    Print byte count
+  -h, --help     Print this help
+";
+
+#[derive(Debug, PartialEq)]
+pub enum Config {
+    Help,
+    Count { lines: bool, words: bool, bytes: bool, file: Option<String> },
+}
+
+/// Parses command-line arguments (without program name). When no count is selected, all of them are printed.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Config, String> {
+    let (mut lines, mut words, mut bytes, mut file) = (false, false, false, None);
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Config::Help),
+            "-l" | "--lines" => lines = true,
+            "-w" | "--words" => words = true,
+            "-c" | "--bytes" => bytes = true,
+            option if option.starts_with('-') => return Err(format!("unknown option '{}'", option)),
+            _ if file.is_some() => return Err(format!("unexpected argument '{}'", arg)),
+            _ => file = Some(arg),
+        }
+    }
+    if !(lines || words || bytes) {
+        lines = true;
+        words = true;
+        bytes = true;
+    }
+    Ok(Config::Count { lines, words, bytes, file })
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub bytes: usize,
+}
+
+/// Counts newlines, whitespace-separated words and bytes of `reader`, line by line.
+pub fn count<R: BufRead>(mut reader: R) -> io::Result<Counts> {
+    let mut counts = Counts::default();
+    let mut line = Vec::new();
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        if line.ends_with(b"\n") {
+            counts.lines += 1;
+        }
+        counts.words += String::from_utf8_lossy(&line).split_whitespace().count();
+        counts.bytes += line.len();
+        line.clear();
+    }
+    Ok(counts)
+}
+
+fn execute<R: BufRead, W: Write>(config: Config, stdin: R, stdout: &mut W) -> Result<(), String> {
+    let (lines, words, bytes, file) = match config {
+        Config::Help => return write!(stdout, "{}", USAGE).map_err(|error| error.to_string()),
+        Config::Count { lines, words, bytes, file } => (lines, words, bytes, file),
+    };
+    let counts = match file {
+        Some(ref path) => File::open(path).and_then(|file| count(BufReader::new(file)))
+            .map_err(|error| format!("cannot read '{}': {}", path, error))?,
+        None => count(stdin).map_err(|error| format!("cannot read standard input: {}", error))?,
+    };
+
+    let mut output = vec![];
+    if lines {
+        output.push(counts.lines.to_string());
+    }
+    if words {
+        output.push(counts.words.to_string());
+    }
+    if bytes {
+        output.push(counts.bytes.to_string());
+    }
+    if let Some(path) = file {
+        output.push(path);
+    }
+    writeln!(stdout, "{}", output.join(" ")).map_err(|error| error.to_string())
+}
+
+/// Runs `count` program and returns its exit code: `0` on success, `1` on runtime error, `2` on usage error.
+pub fn run<I, R, W, E>(args: I, stdin: R, stdout: &mut W, stderr: &mut E) -> i32 where
+    I: IntoIterator<Item = String>, R: BufRead, W: Write, E: Write {
+    let config = match parse_args(args) {
+        Ok(config) => config,
+        Err(message) => {
+            let _ = writeln!(stderr, "count: {}\nTry 'count --help' for more information.", message);
+            return 2;
+        },
+    };
+    match execute(config, stdin, stdout) {
+        Ok(()) => 0,
+        Err(message) => {
+            let _ = writeln!(stderr, "count: {}", message);
+            1
+        },
+    }
+}
@@ -0,0 +1,20 @@
+//! Solution of `25-cli/src/main.rs`, named so that it doesn't collide with `count` binary of `25-cli`.
+
+extern crate solutions;
+
+use std::env;
+use std::io::{self, Write};
+use std::process;
+
+use solutions::_25_cli::run;
+
+fn main() {
+    let stdin = io::stdin();
+    let code = run(env::args().skip(1), stdin.lock(), &mut io::stdout(), &mut io::stderr());
+    // `exit` doesn't run destructors, so buffered output must be written first
+    if let Err(error) = io::stdout().flush() {
+        eprintln!("count: cannot write standard output: {}", error);
+        process::exit(1);
+    }
+    process::exit(code);
+}
@@ -1,4 +1,15 @@
 
+// Used by `_32_no_std` solution, written against `core` as in `32-no_std`
+#[cfg(test)]
+extern crate core;
+
+// Used by `alloc` parts of `_32_no_std` solution, as in `32-no_std`
+#[cfg(all(test, feature = "alloc"))]
+extern crate alloc;
+
+pub mod _20_testing;
+pub mod _25_cli;
+
 #[cfg(test)]
 mod test {
 	mod _01_basic;
@@ -14,4 +25,23 @@ mod test {
 	mod _11_collections;
 	mod _12_parallelism;
 	mod _13_memory;
+	mod _14_iterator;
+	mod _15_module;
+	mod _16_macro;
+	mod _17_shared_state;
+	mod _19_unsafe;
+	mod _20_testing;
+	mod _21_trait_object;
+	mod _22_string;
+	mod _23_parser;
+	mod _24_io;
+	mod _25_cli;
+	mod _26_network;
+	mod _27_smart_pointer;
+	mod _28_type_level;
+	mod _29_advanced_lifetime;
+	mod _30_error_type;
+	mod _31_performance;
+	mod _32_no_std;
+	mod _33_state_machine;
 }
@@ -1,18 +1,114 @@
 #![allow(dead_code)]
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
 enum Formula {
 	True, False,
+	Var(String),
+	Not(Box<Formula>),
 	And(Box<Formula>, Box<Formula>), Or(Box<Formula>, Box<Formula>),
+	Implies(Box<Formula>, Box<Formula>), Iff(Box<Formula>, Box<Formula>),
+}
+
+/// Values of variables, sorted by name.
+type Assignment = BTreeMap<String, bool>;
+
+/// Builds an assignment from `(name, value)` pairs.
+fn assignment(values: &[(&str, bool)]) -> Assignment {
+	values.iter().map(|&(name, value)| (String::from(name), value)).collect()
 }
+
 impl Formula {
+	/// Resolves a formula without variable.
 	fn resolve(&self) -> bool {
+		self.evaluate(&Assignment::new()).expect("formula must not contain variables")
+	}
+
+	/// Resolves formula with variable values from `assignment`. Returns `None` when a variable is missing.
+	fn evaluate(&self, assignment: &Assignment) -> Option<bool> {
+		match self {
+			&Formula::True => Some(true),
+			&Formula::False => Some(false),
+			&Formula::Var(ref name) => assignment.get(name).cloned(),
+			&Formula::Not(ref inner) => inner.evaluate(assignment).map(|value| !value),
+			&Formula::And(ref left, ref right) => operands(left, right, assignment).map(|(left, right)| left && right),
+			&Formula::Or(ref left, ref right) => operands(left, right, assignment).map(|(left, right)| left || right),
+			&Formula::Implies(ref left, ref right) => operands(left, right, assignment).map(|(left, right)| !left || right),
+			&Formula::Iff(ref left, ref right) => operands(left, right, assignment).map(|(left, right)| left == right),
+		}
+	}
+
+	/// Names of all variables, sorted.
+	fn variables(&self) -> BTreeSet<String> {
+		let mut names = BTreeSet::new();
+		self.collect_variables(&mut names);
+		names
+	}
+
+	fn collect_variables(&self, names: &mut BTreeSet<String>) {
 		match self {
-			&Formula::True => true,
-			&Formula::False => false,
-			&Formula::And(ref left, ref right) => left.resolve() && right.resolve(),
-			&Formula::Or(ref left, ref right) => left.resolve() || right.resolve(),
+			&Formula::True | &Formula::False => {},
+			&Formula::Var(ref name) => { names.insert(name.clone()); },
+			&Formula::Not(ref inner) => inner.collect_variables(names),
+			&Formula::And(ref left, ref right) | &Formula::Or(ref left, ref right)
+			| &Formula::Implies(ref left, ref right) | &Formula::Iff(ref left, ref right) => {
+				left.collect_variables(names);
+				right.collect_variables(names);
+			},
 		}
 	}
+
+	/// Higher binds tighter: `!` > `&` > `|` > `->` > `<->`.
+	fn precedence(&self) -> u8 {
+		match self {
+			&Formula::Iff(..) => 1,
+			&Formula::Implies(..) => 2,
+			&Formula::Or(..) => 3,
+			&Formula::And(..) => 4,
+			&Formula::Not(..) => 5,
+			_ => 6,
+		}
+	}
+}
+
+/// Evaluates both sides, so that a missing variable isn't hidden by `&&` or `||` short-circuit.
+fn operands(left: &Formula, right: &Formula, assignment: &Assignment) -> Option<(bool, bool)> {
+	Some((left.evaluate(assignment)?, right.evaluate(assignment)?))
+}
+
+fn write_operand(f: &mut fmt::Formatter, operand: &Formula, parenthesized: bool) -> fmt::Result {
+	if parenthesized {
+		write!(f, "({})", operand)
+	} else {
+		write!(f, "{}", operand)
+	}
+}
+
+/// Writes only parentheses required for `parse()` to give same formula back.
+impl fmt::Display for Formula {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let (left, operator, right) = match self {
+			&Formula::True => return write!(f, "true"),
+			&Formula::False => return write!(f, "false"),
+			&Formula::Var(ref name) => return write!(f, "{}", name),
+			&Formula::Not(ref inner) => {
+				write!(f, "!")?;
+				return write_operand(f, inner, inner.precedence() < self.precedence());
+			},
+			&Formula::And(ref left, ref right) => (left, "&", right),
+			&Formula::Or(ref left, ref right) => (left, "|", right),
+			&Formula::Implies(ref left, ref right) => (left, "->", right),
+			&Formula::Iff(ref left, ref right) => (left, "<->", right),
+		};
+		// `->` groups from right (`a -> b -> c` is `a -> (b -> c)`), other operators from left
+		let precedence = self.precedence();
+		let right_grouped = operator == "->";
+		write_operand(f, left, left.precedence() < precedence || (left.precedence() == precedence && right_grouped))?;
+		write!(f, " {} ", operator)?;
+		write_operand(f, right, right.precedence() < precedence || (right.precedence() == precedence && !right_grouped))
+	}
 }
 
 fn and(left: Formula, right: Formula) -> Formula {
@@ -21,6 +117,217 @@ fn and(left: Formula, right: Formula) -> Formula {
 fn or(left: Formula, right: Formula) -> Formula {
 	Formula::Or(Box::new(left), Box::new(right))
 }
+fn not(inner: Formula) -> Formula {
+	Formula::Not(Box::new(inner))
+}
+fn implies(left: Formula, right: Formula) -> Formula {
+	Formula::Implies(Box::new(left), Box::new(right))
+}
+fn iff(left: Formula, right: Formula) -> Formula {
+	Formula::Iff(Box::new(left), Box::new(right))
+}
+/// Variable named `name`, made of letters, digits and `_`. Panics on names which wouldn't be parsed back,
+/// such as `true` or `a & b`.
+fn var(name: &str) -> Formula {
+	assert!(is_name(name), "invalid variable name '{}'", name);
+	Formula::Var(String::from(name))
+}
+
+fn is_name_char(c: char) -> bool {
+	c.is_alphanumeric() || c == '_'
+}
+
+fn is_name(token: &str) -> bool {
+	!token.is_empty() && token != "true" && token != "false" && token.chars().all(is_name_char)
+}
+
+fn tokenize(input: &str) -> Result<Vec<String>, String> {
+	let mut tokens = vec![];
+	let mut chars = input.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			c if c.is_whitespace() => {},
+			'!' | '&' | '|' | '(' | ')' => tokens.push(c.to_string()),
+			'-' if chars.next_if_eq(&'>').is_some() => tokens.push(String::from("->")),
+			'<' if chars.next_if_eq(&'-').is_some() && chars.next_if_eq(&'>').is_some() => tokens.push(String::from("<->")),
+			c if is_name_char(c) => {
+				let mut name = c.to_string();
+				while let Some(next) = chars.next_if(|&next| is_name_char(next)) {
+					name.push(next);
+				}
+				tokens.push(name);
+			},
+			c => return Err(format!("unexpected character '{}'", c)),
+		}
+	}
+	Ok(tokens)
+}
+
+/// Recursive descent parser, with one method per precedence level.
+struct Parser {
+	tokens: Vec<String>,
+	position: usize,
+}
+
+impl Parser {
+	fn peek(&self) -> Option<&str> {
+		self.tokens.get(self.position).map(|token| token.as_str())
+	}
+
+	fn eat(&mut self, token: &str) -> bool {
+		let found = self.peek() == Some(token);
+		if found {
+			self.position += 1;
+		}
+		found
+	}
+
+	fn iff(&mut self) -> Result<Formula, String> {
+		let mut formula = self.implies()?;
+		while self.eat("<->") {
+			formula = iff(formula, self.implies()?);
+		}
+		Ok(formula)
+	}
+
+	fn implies(&mut self) -> Result<Formula, String> {
+		let formula = self.or()?;
+		if self.eat("->") {
+			Ok(implies(formula, self.implies()?))
+		} else {
+			Ok(formula)
+		}
+	}
+
+	fn or(&mut self) -> Result<Formula, String> {
+		let mut formula = self.and()?;
+		while self.eat("|") {
+			formula = or(formula, self.and()?);
+		}
+		Ok(formula)
+	}
+
+	fn and(&mut self) -> Result<Formula, String> {
+		let mut formula = self.not()?;
+		while self.eat("&") {
+			formula = and(formula, self.not()?);
+		}
+		Ok(formula)
+	}
+
+	fn not(&mut self) -> Result<Formula, String> {
+		if self.eat("!") {
+			Ok(not(self.not()?))
+		} else {
+			self.atom()
+		}
+	}
+
+	fn atom(&mut self) -> Result<Formula, String> {
+		let token = match self.peek() {
+			Some(token) => token.to_string(),
+			None => return Err(String::from("unexpected end of formula")),
+		};
+		self.position += 1;
+		match token.as_str() {
+			"(" => {
+				let formula = self.iff()?;
+				if self.eat(")") {
+					Ok(formula)
+				} else {
+					Err(String::from("expected ')'"))
+				}
+			},
+			"true" => Ok(Formula::True),
+			"false" => Ok(Formula::False),
+			name if is_name(name) => Ok(var(name)),
+			other => Err(format!("unexpected '{}'", other)),
+		}
+	}
+}
+
+/// Parses a formula like `a & (b | !c)`. Operators from lowest to highest precedence: `<->`, `->`, `|`, `&` and `!`.
+fn parse(input: &str) -> Result<Formula, String> {
+	let mut parser = Parser { tokens: tokenize(input)?, position: 0 };
+	let formula = parser.iff()?;
+	match parser.peek() {
+		None => Ok(formula),
+		Some(token) => Err(format!("unexpected '{}'", token)),
+	}
+}
+
+/// All `2^n` assignments of `variables`, from all `false` to all `true`. Last variable changes first.
+/// They are built one at a time, as they're needed. Panics with 64 variables or more.
+fn assignments<'a>(variables: &'a BTreeSet<String>) -> impl Iterator<Item = Assignment> + 'a {
+	let count = variables.len();
+	assert!(count < 64, "too many variables: {}", count);
+	(0..1u64 << count).map(move |row| {
+		variables.iter().enumerate()
+			.map(|(index, name)| (name.clone(), row >> (count - 1 - index) & 1 == 1))
+			.collect()
+	})
+}
+
+/// Value of a formula for each assignment of its variables.
+struct TruthTable {
+	formula: Formula,
+	variables: Vec<String>,
+	rows: Vec<(Assignment, bool)>,
+}
+
+fn truth_table(formula: &Formula) -> TruthTable {
+	let variables = formula.variables();
+	let rows = assignments(&variables)
+		.map(|assignment| {
+			let value = formula.evaluate(&assignment).expect("all variables are assigned");
+			(assignment, value)
+		})
+		.collect();
+	TruthTable { formula: formula.clone(), variables: variables.into_iter().collect(), rows }
+}
+
+/// Renders one column per variable, then formula column, with `T` and `F` values:
+///
+/// ```text
+/// a | b | a & b
+/// --+---+------
+/// F | F | F
+/// ```
+impl fmt::Display for TruthTable {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut headers: Vec<String> = self.variables.clone();
+		headers.push(self.formula.to_string());
+		let widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+		writeln!(f, "{}", headers.join(" | "))?;
+		let separators: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+		writeln!(f, "{}", separators.join("-+-"))?;
+		for &(ref assignment, value) in &self.rows {
+			let values: Vec<bool> = self.variables.iter().map(|name| assignment[name]).chain(Some(value)).collect();
+			let cells: Vec<String> = values.iter().zip(&widths)
+				.map(|(&value, &width)| format!("{:<width$}", if value { "T" } else { "F" }, width = width))
+				.collect();
+			writeln!(f, "{}", cells.join(" | ").trim_end())?;
+		}
+		Ok(())
+	}
+}
+
+/// Checks that `left` and `right` have same value for every assignment of their variables.
+/// Returns first assignment giving different values otherwise.
+fn equivalent(left: &Formula, right: &Formula) -> Result<(), Assignment> {
+	let variables = left.variables().union(&right.variables()).cloned().collect();
+	let counterexample = assignments(&variables).find(|assignment| left.evaluate(assignment) != right.evaluate(assignment));
+	match counterexample {
+		Some(counterexample) => Err(counterexample),
+		None => Ok(()),
+	}
+}
+
+/// Checks that `formula` is `true` for every assignment of its variables.
+/// Returns first assignment making it `false` otherwise.
+fn is_tautology(formula: &Formula) -> Result<(), Assignment> {
+	equivalent(formula, &Formula::True)
+}
 
 mod formula_should {
 	use super::{Formula::{self, True, False}, and, or};
@@ -55,6 +362,291 @@ mod formula_should {
 	}
 }
 
+mod evaluate_should {
+	use super::{Formula::{True, False}, assignment, and, or, not, implies, iff, var};
+
+	#[test]
+	fn resolve_negation() {
+		assert!(not(False).resolve());
+		assert!(!not(True).resolve());
+	}
+
+	#[test]
+	fn resolve_implication() {
+		assert!(implies(False, False).resolve());
+		assert!(implies(False, True).resolve());
+		assert!(!implies(True, False).resolve());
+		assert!(implies(True, True).resolve());
+	}
+
+	#[test]
+	fn resolve_equivalence() {
+		assert!(iff(False, False).resolve());
+		assert!(!iff(False, True).resolve());
+		assert!(!iff(True, False).resolve());
+		assert!(iff(True, True).resolve());
+	}
+
+	#[test]
+	fn read_variables_from_assignment() {
+		let formula = and(var("a"), or(var("b"), not(var("c"))));
+		assert_eq!(Some(true), formula.evaluate(&assignment(&[("a", true), ("b", false), ("c", false)])));
+		assert_eq!(Some(false), formula.evaluate(&assignment(&[("a", true), ("b", false), ("c", true)])));
+		assert_eq!(Some(false), formula.evaluate(&assignment(&[("a", false), ("b", true), ("c", true)])));
+	}
+
+	#[test]
+	fn return_none_when_variable_is_missing() {
+		assert_eq!(None, or(True, var("a")).evaluate(&assignment(&[("b", true)])));
+		assert_eq!(Some(true), var("a").evaluate(&assignment(&[("a", true), ("unused", false)])));
+	}
+
+	#[test]
+	#[should_panic(expected = "formula must not contain variables")]
+	fn not_resolve_variables() {
+		var("a").resolve();
+	}
+}
+
+mod display_should {
+	use super::{Formula::{True, False}, and, or, not, implies, iff, var};
+
+	#[test]
+	fn write_constants_and_variables() {
+		assert_eq!("true", True.to_string());
+		assert_eq!("false", False.to_string());
+		assert_eq!("rainy_day", var("rainy_day").to_string());
+	}
+
+	#[test]
+	fn write_operators() {
+		assert_eq!("!a", not(var("a")).to_string());
+		assert_eq!("a & b", and(var("a"), var("b")).to_string());
+		assert_eq!("a | b", or(var("a"), var("b")).to_string());
+		assert_eq!("a -> b", implies(var("a"), var("b")).to_string());
+		assert_eq!("a <-> b", iff(var("a"), var("b")).to_string());
+	}
+
+	#[test]
+	fn omit_parentheses_when_precedence_is_enough() {
+		assert_eq!("a & b | c", or(and(var("a"), var("b")), var("c")).to_string());
+		assert_eq!("!a & b -> c <-> d", iff(implies(and(not(var("a")), var("b")), var("c")), var("d")).to_string());
+		assert_eq!("!!a", not(not(var("a"))).to_string());
+	}
+
+	#[test]
+	fn write_parentheses_when_precedence_is_not_enough() {
+		assert_eq!("a & (b | !c)", and(var("a"), or(var("b"), not(var("c")))).to_string());
+		assert_eq!("!(a & b)", not(and(var("a"), var("b"))).to_string());
+		assert_eq!("(a <-> b) -> c", implies(iff(var("a"), var("b")), var("c")).to_string());
+	}
+
+	#[test]
+	fn follow_grouping_direction() {
+		assert_eq!("a & b & c", and(and(var("a"), var("b")), var("c")).to_string());
+		assert_eq!("a & (b & c)", and(var("a"), and(var("b"), var("c"))).to_string());
+		assert_eq!("a -> b -> c", implies(var("a"), implies(var("b"), var("c"))).to_string());
+		assert_eq!("(a -> b) -> c", implies(implies(var("a"), var("b")), var("c")).to_string());
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_empty_variable_name() {
+		var("");
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_constant_as_variable_name() {
+		var("true");
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid variable name")]
+	fn reject_operator_in_variable_name() {
+		var("a & b");
+	}
+}
+
+mod parse_should {
+	use super::{Formula::{True, False}, parse, and, or, not, implies, iff, var};
+
+	#[test]
+	fn parse_nested_formula() {
+		assert_eq!(Ok(and(var("a"), or(var("b"), not(var("c"))))), parse("a & (b | !c)"));
+	}
+
+	#[test]
+	fn parse_constants_and_names() {
+		assert_eq!(Ok(implies(True, or(var("x_1"), False))), parse("  true->x_1|false "));
+	}
+
+	#[test]
+	fn follow_precedence() {
+		assert_eq!(Ok(or(var("a"), and(var("b"), var("c")))), parse("a | b & c"));
+		assert_eq!(Ok(and(not(var("a")), var("b"))), parse("!a & b"));
+		assert_eq!(Ok(iff(var("a"), implies(var("b"), var("c")))), parse("a <-> b -> c"));
+	}
+
+	#[test]
+	fn follow_grouping_direction() {
+		assert_eq!(Ok(or(or(var("a"), var("b")), var("c"))), parse("a | b | c"));
+		assert_eq!(Ok(implies(var("a"), implies(var("b"), var("c")))), parse("a -> b -> c"));
+		assert_eq!(Ok(iff(iff(var("a"), var("b")), var("c"))), parse("a <-> b <-> c"));
+	}
+
+	#[test]
+	fn parse_displayed_formula_back() {
+		let formulas = vec![
+			and(var("a"), and(var("b"), var("c"))),
+			implies(implies(var("a"), var("b")), var("c")),
+			not(or(var("a"), not(iff(True, var("b"))))),
+			iff(and(var("p"), implies(var("q"), False)), or(var("r"), var("p"))),
+		];
+		for formula in formulas {
+			assert_eq!(Ok(formula.clone()), parse(&formula.to_string()), "{}", formula);
+		}
+	}
+
+	#[test]
+	fn report_errors() {
+		assert_eq!(Err(String::from("unexpected end of formula")), parse(""));
+		assert_eq!(Err(String::from("unexpected end of formula")), parse("a &"));
+		assert_eq!(Err(String::from("expected ')'")), parse("(a | b"));
+		assert_eq!(Err(String::from("unexpected 'b'")), parse("a b"));
+		assert_eq!(Err(String::from("unexpected ')'")), parse("a)"));
+		assert_eq!(Err(String::from("unexpected '&'")), parse("& a"));
+		assert_eq!(Err(String::from("unexpected character '#'")), parse("a # b"));
+		assert_eq!(Err(String::from("unexpected character '<'")), parse("a <- b"));
+	}
+}
+
+mod truth_table_should {
+	use super::{Assignment, Formula::{True, False}, assignment, assignments, truth_table, and, or, not, implies, var};
+
+	#[test]
+	fn enumerate_all_assignments() {
+		let variables = vec![String::from("a"), String::from("b")].into_iter().collect();
+		assert_eq!(
+			vec![
+				assignment(&[("a", false), ("b", false)]),
+				assignment(&[("a", false), ("b", true)]),
+				assignment(&[("a", true), ("b", false)]),
+				assignment(&[("a", true), ("b", true)]),
+			],
+			assignments(&variables).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn enumerate_lazily() {
+		let variables = (0..63).map(|index| format!("x{}", index)).collect();
+		assert_eq!(2, assignments(&variables).take(2).count());
+	}
+
+	#[test]
+	#[should_panic(expected = "too many variables")]
+	fn reject_64_variables() {
+		let variables = (0..64).map(|index| format!("x{}", index)).collect();
+		assignments(&variables).next();
+	}
+
+	#[test]
+	fn have_a_single_row_without_variable() {
+		let table = truth_table(&or(True, False));
+		assert!(table.variables.is_empty());
+		assert_eq!(vec![(Assignment::new(), true)], table.rows);
+	}
+
+	#[test]
+	fn sort_and_deduplicate_variables() {
+		let table = truth_table(&and(var("c"), or(var("a"), not(var("c")))));
+		assert_eq!(vec!["a", "c"], table.variables);
+		assert_eq!(4, table.rows.len());
+		assert_eq!((assignment(&[("a", true), ("c", true)]), true), table.rows[3]);
+	}
+
+	#[test]
+	fn render_table() {
+		assert_eq!(
+			"a | b | a -> b\n\
+			 --+---+-------\n\
+			 F | F | T\n\
+			 F | T | T\n\
+			 T | F | F\n\
+			 T | T | T\n",
+			truth_table(&implies(var("a"), var("b"))).to_string()
+		);
+	}
+
+	#[test]
+	fn render_table_without_variable() {
+		assert_eq!("!true\n-----\nF\n", truth_table(&not(True)).to_string());
+	}
+}
+
+mod equivalence_should {
+	use super::{Assignment, Formula::{True, False}, assignment, equivalent, is_tautology, parse, and, or, not, implies, iff, var};
+
+	#[test]
+	fn accept_de_morgan_laws() {
+		assert_eq!(Ok(()), equivalent(&not(and(var("a"), var("b"))), &or(not(var("a")), not(var("b")))));
+		assert_eq!(Ok(()), equivalent(&not(or(var("a"), var("b"))), &and(not(var("a")), not(var("b")))));
+	}
+
+	#[test]
+	fn give_counterexample_when_not_equivalent() {
+		assert_eq!(Err(assignment(&[("a", false), ("b", true)])), equivalent(&implies(var("a"), var("b")), &implies(var("b"), var("a"))));
+	}
+
+	#[test]
+	fn assign_variables_of_both_formulas() {
+		assert_eq!(Err(assignment(&[("a", false), ("b", false)])), equivalent(&var("a"), &or(var("a"), not(var("b")))));
+		assert_eq!(Ok(()), equivalent(&and(var("a"), or(var("b"), not(var("b")))), &var("a")));
+	}
+
+	#[test]
+	fn stop_at_first_counterexample() {
+		// 2^40 assignments couldn't all be checked
+		let names: Vec<String> = (0..40).map(|index| format!("x{}", index)).collect();
+		let all = names.iter().skip(1).fold(var(&names[0]), |formula, name| and(formula, var(name)));
+		let counterexample = names.iter().map(|name| (name.clone(), false)).collect();
+		assert_eq!(Err(counterexample), equivalent(&all, &not(var("x0"))));
+	}
+
+	#[test]
+	fn check_simplifications() {
+		let simplifications = vec![
+			(and(True, True), True),
+			(and(True, False), False),
+			(or(False, False), False),
+			(or(True, False), True),
+			(and(True, var("x")), var("x")),
+			(or(False, var("x")), var("x")),
+			(and(var("x"), or(var("x"), var("y"))), var("x")),
+			(or(and(var("x"), var("y")), and(var("x"), not(var("y")))), var("x")),
+			(implies(var("x"), var("y")), or(not(var("x")), var("y"))),
+			(iff(var("x"), var("y")), and(implies(var("x"), var("y")), implies(var("y"), var("x")))),
+		];
+		for (formula, simplified) in simplifications {
+			assert_eq!(Ok(()), equivalent(&formula, &simplified), "{} should be {}", formula, simplified);
+		}
+	}
+
+	#[test]
+	fn accept_tautologies() {
+		assert_eq!(Ok(()), is_tautology(&True));
+		assert_eq!(Ok(()), is_tautology(&or(var("a"), not(var("a")))));
+		assert_eq!(Ok(()), is_tautology(&parse("(a -> b) & (b -> c) -> a -> c").unwrap()));
+	}
+
+	#[test]
+	fn give_counterexample_when_not_a_tautology() {
+		assert_eq!(Err(Assignment::new()), is_tautology(&False));
+		assert_eq!(Err(assignment(&[("a", true), ("b", false)])), is_tautology(&implies(var("a"), var("b"))));
+	}
+}
+
 /// # spy
 /// Implements a very simple spy which counts application calls.
 mod spy {
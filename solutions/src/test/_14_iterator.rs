@@ -0,0 +1,345 @@
+#![allow(dead_code)]
+
+use std::iter::Fuse;
+
+/// Counts down from `from` to `1` (both included).
+///
+/// For example: `Countdown::new(3) = 3, 2, 1`
+///
+struct Countdown {
+    current: u32,
+}
+
+impl Countdown {
+    fn new(from: u32) -> Self {
+        Countdown { current: from }
+    }
+}
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.current == 0 {
+            None
+        } else {
+            self.current -= 1;
+            Some(self.current + 1)
+        }
+    }
+}
+
+mod countdown_should {
+    use super::*;
+
+    #[test]
+    fn return_nothing_when_from_0() {
+        assert_eq!(Vec::<u32>::new(), Countdown::new(0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn return_3_2_1_when_from_3() {
+        assert_eq!(vec![3, 2, 1], Countdown::new(3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn keep_returning_none_when_over() {
+        let mut countdown = Countdown::new(1);
+        assert_eq!(Some(1), countdown.next());
+        assert_eq!(None, countdown.next());
+        assert_eq!(None, countdown.next());
+    }
+
+    #[test]
+    fn support_adapters() {
+        let sum: u32 = Countdown::new(10).filter(|n| n % 2 == 0).sum();
+        assert_eq!(30, sum);
+    }
+}
+
+/// Infinite iterator over Fibonacci numbers.
+///
+/// For example: `0, 1, 1, 2, 3, 5, 8, 13, ...`
+///
+struct Fibonacci {
+    current: u64,
+    next: u64,
+}
+
+impl Fibonacci {
+    fn new() -> Self {
+        Fibonacci { current: 0, next: 1 }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.current;
+        self.current = self.next;
+        self.next += current;
+        Some(current)
+    }
+}
+
+mod fibonacci_should {
+    use super::*;
+
+    #[test]
+    fn start_with_0_1_1_2_3_5_8_13() {
+        assert_eq!(vec![0, 1, 1, 2, 3, 5, 8, 13], Fibonacci::new().take(8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn return_6765_as_20th() {
+        assert_eq!(Some(6765), Fibonacci::new().nth(20));
+    }
+
+    #[test]
+    fn find_first_above_1000() {
+        assert_eq!(Some(1597), Fibonacci::new().find(|n| *n > 1000));
+    }
+}
+
+/// Adapter returning each item with its successor.
+///
+/// For example: `[1, 2, 3, 4].pairs() = (1, 2), (2, 3), (3, 4)`
+///
+struct Pairs<I: Iterator> {
+    /// Fused, so that no pair is returned after a `None`, even if `I` resumes.
+    iter: Fuse<I>,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for Pairs<I> where
+    I: Iterator,
+    I::Item: Clone {
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous.is_none() {
+            self.previous = self.iter.next();
+        }
+        let previous = self.previous.take()?;
+        let current = self.iter.next()?;
+        self.previous = Some(current.clone());
+        Some((previous, current))
+    }
+}
+
+trait PairsExt: Iterator + Sized {
+    fn pairs(self) -> Pairs<Self>;
+}
+
+impl<I> PairsExt for I where
+    I: Iterator,
+    I::Item: Clone {
+    fn pairs(self) -> Pairs<Self> {
+        Pairs { iter: self.fuse(), previous: None }
+    }
+}
+
+mod pairs_should {
+    use super::*;
+
+    #[test]
+    fn return_nothing_when_empty() {
+        assert_eq!(Vec::<(u8, u8)>::new(), Vec::<u8>::new().into_iter().pairs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn return_nothing_when_single() {
+        assert_eq!(Vec::<(u8, u8)>::new(), vec![1u8].into_iter().pairs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn return_1n2_2n3_3n4_when_1_2_3_4() {
+        assert_eq!(vec![(1, 2), (2, 3), (3, 4)], vec![1, 2, 3, 4].into_iter().pairs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn support_non_copy_items() {
+        let words = vec![String::from("foo"), String::from("bar"), String::from("baz")];
+        let pairs: Vec<_> = words.into_iter().pairs().map(|(a, b)| format!("{}-{}", a, b)).collect();
+        assert_eq!(vec!["foo-bar", "bar-baz"], pairs);
+    }
+
+    #[test]
+    fn support_infinite_iterators() {
+        assert_eq!(vec![(0, 1), (1, 2)], (0..).pairs().take(2).collect::<Vec<_>>());
+    }
+
+    /// Returns `Some(1), Some(2), None, Some(3), Some(4), None, ...`
+    struct Resuming(u8);
+
+    impl Iterator for Resuming {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0 += 1;
+            if self.0 % 3 == 0 { None } else { Some(self.0 - self.0 / 3) }
+        }
+    }
+
+    #[test]
+    fn return_nothing_after_end() {
+        let mut pairs = Resuming(0).pairs();
+        assert_eq!(Some((1, 2)), pairs.next());
+        assert_eq!(None, pairs.next());
+        assert_eq!(None, pairs.next());
+        assert_eq!(None, pairs.next());
+    }
+}
+
+/// Returns consecutive numbers followed by their sum (see 11-collections), but using `pairs()`.
+///
+/// For example: `followed_by_sum([1, 4, 5, 9]) = [(1, 4), (4, 5)]`
+///
+fn followed_by_sum(numbers: Vec<u64>) -> Vec<(u64, u64)> {
+    numbers.into_iter()
+        .pairs()
+        .pairs()
+        .filter(|&((a, b), (_, c))| a + b == c)
+        .map(|(first, _)| first)
+        .collect()
+}
+
+mod followed_by_sum_should {
+    use super::followed_by_sum;
+
+    #[test]
+    fn returns_empty_when_contains_1_2() {
+        assert_eq!(Vec::<(u64, u64)>::new(), followed_by_sum(vec![1, 2]));
+    }
+
+    #[test]
+    fn returns_1n2_when_contains_1_2_3() {
+        assert_eq!(vec![(1, 2)], followed_by_sum(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn returns_1n4_4n5_when_contains_1_4_5_9() {
+        assert_eq!(vec![(1, 4),(4, 5)], followed_by_sum(vec![1, 4, 5, 9]));
+    }
+}
+
+/// Ordered list of song titles.
+struct Playlist {
+    songs: Vec<String>,
+}
+
+impl Playlist {
+    fn new(songs: &[&str]) -> Self {
+        Playlist { songs: songs.iter().map(|song| String::from(*song)).collect() }
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.songs.iter()
+    }
+
+    fn iter_mut(&mut self) -> std::slice::IterMut<'_, String> {
+        self.songs.iter_mut()
+    }
+}
+
+impl IntoIterator for Playlist {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.songs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Playlist {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Playlist {
+    type Item = &'a mut String;
+    type IntoIter = std::slice::IterMut<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+mod playlist_should {
+    use super::*;
+
+    #[test]
+    fn be_iterable_by_reference() {
+        let playlist = Playlist::new(&["Help!", "Yesterday"]);
+        let mut songs: Vec<&String> = vec![];
+        for song in &playlist {
+            songs.push(song);
+        }
+        assert_eq!(vec!["Help!", "Yesterday"], songs);
+        assert_eq!(2, playlist.iter().count());
+    }
+
+    #[test]
+    fn be_iterable_by_mutable_reference() {
+        let mut playlist = Playlist::new(&["Help!", "Yesterday"]);
+        for song in &mut playlist {
+            song.push_str(" (remastered)");
+        }
+        assert_eq!(vec!["Help! (remastered)", "Yesterday (remastered)"], playlist.songs);
+    }
+
+    #[test]
+    fn be_iterable_by_value() {
+        let playlist = Playlist::new(&["Help!", "Yesterday"]);
+        let mut songs: Vec<String> = vec![];
+        for song in playlist {
+            songs.push(song);
+        }
+        assert_eq!(vec!["Help!", "Yesterday"], songs);
+    }
+
+    #[test]
+    fn provide_iter_mut() {
+        let mut playlist = Playlist::new(&["help!"]);
+        for song in playlist.iter_mut() {
+            *song = song.to_uppercase();
+        }
+        assert_eq!(vec!["HELP!"], playlist.songs);
+    }
+}
+
+/// Returns squares of `numbers` greater than `threshold`, without consuming more numbers than needed.
+///
+/// For example: `squares_above(1.., 10).take(2) = 16, 25`
+///
+fn squares_above<I>(numbers: I, threshold: u64) -> impl Iterator<Item=u64> where
+    I: Iterator<Item=u64> {
+    numbers.map(|n| n * n).filter(move |square| *square > threshold)
+}
+
+mod squares_above_should {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn return_16_25_when_taking_2_above_10() {
+        assert_eq!(vec![16, 25], squares_above(1.., 10).take(2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn consume_nothing_until_asked() {
+        let consumed = Cell::new(0);
+        let numbers = (1..).inspect(|_| consumed.set(consumed.get() + 1));
+
+        let mut squares = squares_above(numbers, 10);
+        assert_eq!(0, consumed.get());
+
+        assert_eq!(Some(16), squares.next());
+        assert_eq!(4, consumed.get());
+    }
+}
@@ -0,0 +1,8 @@
+//! Online shop split into many files, as real projects do.
+
+mod catalog;
+mod cart;
+mod pricing;
+
+pub use self::catalog::Product;
+pub use self::cart::Cart;
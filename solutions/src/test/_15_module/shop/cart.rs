@@ -0,0 +1,21 @@
+use super::catalog::Product;
+use super::pricing::discount;
+
+pub struct Cart {
+    products: Vec<Product>,
+}
+
+impl Cart {
+    pub fn new() -> Self {
+        Cart { products: vec![] }
+    }
+
+    pub fn add(&mut self, product: Product) {
+        self.products.push(product);
+    }
+
+    pub fn total(&self) -> u64 {
+        let total = self.products.iter().map(|product| product.price()).sum();
+        discount(total)
+    }
+}
@@ -0,0 +1,18 @@
+pub struct Product {
+    name: String,
+    price: u64,
+}
+
+impl Product {
+    pub fn new(name: &str, price: u64) -> Self {
+        Product { name: String::from(name), price }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn price(&self) -> u64 {
+        self.price
+    }
+}
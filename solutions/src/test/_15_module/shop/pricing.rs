@@ -0,0 +1,8 @@
+/// Applies a 10% discount when total is above 100.
+pub(super) fn discount(total: u64) -> u64 {
+    if total > 100 {
+        total - total / 10
+    } else {
+        total
+    }
+}
@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Macros have to be written into `macros.rs` file.
+//!
+
+#[macro_use]
+mod macros;
+
+#[path = "../../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `snippet` (as `main` body) along with `macros.rs`.
+fn compile(name: &str, snippet: &str) -> compiler::Compilation {
+    compiler::compile(name, &compiler::program(include_str!("_16_macro/macros.rs"), snippet), &[])
+}
+
+mod hashmap_should {
+    use std::collections::HashMap;
+    use super::compile;
+
+    #[test]
+    fn create_empty_map() {
+        let map: HashMap<&str, u8> = hashmap!{};
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn create_map_with_one_two() {
+        let map = hashmap!{ "one" => 1, "two" => 2 };
+        assert_eq!(2, map.len());
+        assert_eq!(Some(&1), map.get("one"));
+        assert_eq!(Some(&2), map.get("two"));
+    }
+
+    #[test]
+    fn support_trailing_comma() {
+        let map = hashmap!{
+            "one" => 1,
+            "two" => 2,
+        };
+        assert_eq!(2, map.len());
+    }
+
+    #[test]
+    fn support_expressions() {
+        let key = String::from("forty-two");
+        let map = hashmap!{ key.clone() => 40 + 2 };
+        assert_eq!(Some(&42), map.get(&key));
+    }
+
+    #[test]
+    fn compile_with_pairs() {
+        compile("hashmap_ok", r#"let map = hashmap!{ "one" => 1 };"#).assert_success();
+    }
+
+    #[test]
+    fn not_compile_with_missing_value() {
+        compile("hashmap_missing_value", r#"let map = hashmap!{ "one" => 1, "two" };"#).assert_error("unexpected end of macro invocation");
+    }
+
+    #[test]
+    fn not_compile_with_mixed_value_types() {
+        compile("hashmap_mixed_types", r#"let map = hashmap!{ "one" => 1, "two" => "2" };"#).assert_error("E0308");
+    }
+}
+
+mod count_should {
+    #[test]
+    fn return_0_when_empty() {
+        assert_eq!(0, count!());
+    }
+
+    #[test]
+    fn return_3_when_a_b_c() {
+        assert_eq!(3, count!(a b c));
+    }
+
+    #[test]
+    fn be_evaluated_at_compile_time() {
+        const COUNT: usize = count!(x y z t);
+        let array = [0u8; COUNT];
+        assert_eq!(4, array.len());
+    }
+}
+
+mod max_should {
+    use super::compile;
+
+    #[test]
+    fn return_42_when_42() {
+        assert_eq!(42, max!(42));
+    }
+
+    #[test]
+    fn return_5_when_1_5_3() {
+        assert_eq!(5, max!(1, 5, 3));
+    }
+
+    #[test]
+    fn evaluate_each_expression_once() {
+        let mut calls = 0;
+        let result = max!({ calls += 1; 2 }, { calls += 1; 8 }, { calls += 1; 4 });
+        assert_eq!(8, result);
+        assert_eq!(3, calls);
+    }
+
+    #[test]
+    fn not_compile_when_empty() {
+        compile("max_many", "let max = max!(1, 2);").assert_success();
+        compile("max_empty", "let max = max!();").assert_error("unexpected end of macro invocation");
+    }
+}
+
+builder!(CarBuilder => Car { brand: String, model: String });
+
+mod builder_should {
+    use super::*;
+
+    #[test]
+    fn build_car_with_empty_names_by_default() {
+        let car = CarBuilder::new().build();
+
+        assert_eq!(String::from(""), *car.brand());
+        assert_eq!(String::from(""), *car.model());
+    }
+
+    #[test]
+    fn build_car_using_a_fluent_interface() {
+        let car = CarBuilder::new().brand("Tesla").model("Model S").build();
+
+        assert_eq!(String::from("Tesla"), *car.brand());
+        assert_eq!(String::from("Model S"), *car.model());
+    }
+
+    #[test]
+    fn support_any_field_types() {
+        builder!(PointBuilder => Point { x: i64, y: i64 });
+
+        let point = PointBuilder::new().x(4).build();
+        assert_eq!(4, *point.x());
+        assert_eq!(0, *point.y());
+    }
+
+    #[test]
+    fn compile_with_typed_fields() {
+        compile("builder_ok", "builder!(CarBuilder => Car { brand: String }); CarBuilder::new().brand(\"Tesla\").build();").assert_success();
+    }
+
+    #[test]
+    fn not_compile_with_untyped_field() {
+        compile("builder_typed", "builder!(CarBuilder => Car { brand: String }); CarBuilder::new().build();").assert_success();
+        compile("builder_untyped", "builder!(CarBuilder => Car { brand });").assert_error("no rules expected");
+    }
+}
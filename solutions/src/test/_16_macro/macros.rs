@@ -0,0 +1,81 @@
+/// Builds a `HashMap` from `key => value` pairs.
+///
+/// For example: `hashmap!{ "one" => 1, "two" => 2 }`
+///
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = ::std::collections::HashMap::new();
+        $(
+            map.insert($key, $value);
+        )*
+        map
+    }};
+}
+
+/// Counts tokens given as input.
+///
+/// For example: `count!(a b c) = 3`
+///
+macro_rules! count {
+    () => { 0usize };
+    ($head:tt $($tail:tt)*) => { 1usize + count!($($tail)*) };
+}
+
+/// Returns greatest of all given expressions.
+///
+/// For example: `max!(1, 5, 3) = 5`
+///
+macro_rules! max {
+    ($value:expr) => { $value };
+    ($head:expr, $($tail:expr),+) => {{
+        let head = $head;
+        let tail = max!($($tail),+);
+        if head > tail { head } else { tail }
+    }};
+}
+
+/// Generates a struct with getters and its fluent builder (see 04-ownership).
+///
+/// For example: `builder!(CarBuilder => Car { brand: String, model: String });`
+///
+macro_rules! builder {
+    ($builder:ident => $target:ident { $($field:ident: $type:ty),* $(,)? }) => {
+        #[derive(Debug)]
+        struct $target {
+            $($field: $type,)*
+        }
+
+        impl $target {
+            $(
+                fn $field(&self) -> &$type {
+                    &self.$field
+                }
+            )*
+        }
+
+        #[derive(Default)]
+        struct $builder {
+            $($field: $type,)*
+        }
+
+        impl $builder {
+            fn new() -> Self {
+                Default::default()
+            }
+
+            $(
+                fn $field<V: Into<$type>>(mut self, $field: V) -> Self {
+                    self.$field = $field.into();
+                    self
+                }
+            )*
+
+            fn build(&self) -> $target {
+                $target {
+                    $($field: self.$field.clone(),)*
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,361 @@
+#![allow(dead_code)]
+
+/// Swaps values pointed by `a` and `b`.
+///
+/// # Safety
+///
+/// Both pointers must be valid for reads and writes, and properly aligned.
+///
+unsafe fn swap_raw(a: *mut i32, b: *mut i32) {
+    let tmp = *a;
+    *a = *b;
+    *b = tmp;
+}
+
+mod swap_raw_should {
+    use super::*;
+
+    #[test]
+    fn swap_values() {
+        let mut a = 1;
+        let mut b = 2;
+        unsafe {
+            swap_raw(&mut a, &mut b);
+        }
+        assert_eq!((2, 1), (a, b));
+    }
+
+    #[test]
+    fn support_same_pointer() {
+        let mut a = 42;
+        let pointer: *mut i32 = &mut a;
+        unsafe {
+            swap_raw(pointer, pointer);
+        }
+        assert_eq!(42, a);
+    }
+}
+
+/// Splits `values` into two mutable slices at `mid` (like `slice::split_at_mut`).
+///
+/// _Note: it can't be implemented without `unsafe`, as borrow checker can't know both slices don't overlap._
+///
+fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    assert!(mid <= values.len(), "mid out of bounds");
+    let len = values.len();
+    let pointer = values.as_mut_ptr();
+    unsafe {
+        (
+            std::slice::from_raw_parts_mut(pointer, mid),
+            std::slice::from_raw_parts_mut(pointer.add(mid), len - mid),
+        )
+    }
+}
+
+mod split_at_mut_should {
+    use super::*;
+
+    #[test]
+    fn return_both_halves() {
+        let mut values = [1, 2, 3, 4, 5];
+        let (left, right) = split_at_mut(&mut values, 2);
+        assert_eq!(&mut [1, 2], left);
+        assert_eq!(&mut [3, 4, 5], right);
+    }
+
+    #[test]
+    fn allow_to_mutate_both_halves() {
+        let mut values = [1, 2, 3, 4];
+        {
+            let (left, right) = split_at_mut(&mut values, 2);
+            left[0] = 10;
+            right[0] = 30;
+        }
+        assert_eq!([10, 2, 30, 4], values);
+    }
+
+    #[test]
+    fn return_empty_left_when_0() {
+        let mut values = [1, 2];
+        let (left, right) = split_at_mut(&mut values, 0);
+        assert!(left.is_empty());
+        assert_eq!(&mut [1, 2], right);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_when_out_of_bounds() {
+        let mut values = [1, 2];
+        split_at_mut(&mut values, 3);
+    }
+}
+
+mod libc {
+    use std::os::raw::{c_char, c_int, c_void};
+
+    extern "C" {
+        pub fn strlen(s: *const c_char) -> usize;
+        pub fn qsort(base: *mut c_void, count: usize, size: usize, compare: extern "C" fn(*const c_void, *const c_void) -> c_int);
+    }
+}
+
+/// Returns length of `text` in bytes, computed by C `strlen` function.
+fn c_strlen(text: &str) -> usize {
+    let c_text = std::ffi::CString::new(text).expect("text must not contain NUL byte");
+    unsafe { libc::strlen(c_text.as_ptr()) }
+}
+
+mod c_strlen_should {
+    use super::*;
+
+    #[test]
+    fn return_0_when_empty() {
+        assert_eq!(0, c_strlen(""));
+    }
+
+    #[test]
+    fn return_4_when_rust() {
+        assert_eq!(4, c_strlen("rust"));
+    }
+
+    #[test]
+    fn count_bytes_not_chars() {
+        assert_eq!(3, c_strlen("❤"));
+    }
+}
+
+extern "C" fn compare_i32(left: *const std::os::raw::c_void, right: *const std::os::raw::c_void) -> std::os::raw::c_int {
+    let (left, right) = unsafe { (*(left as *const i32), *(right as *const i32)) };
+    left.cmp(&right) as std::os::raw::c_int
+}
+
+/// Sorts `values` with C `qsort` function.
+fn c_sort(values: &mut [i32]) {
+    unsafe {
+        libc::qsort(values.as_mut_ptr() as *mut std::os::raw::c_void, values.len(), std::mem::size_of::<i32>(), compare_i32);
+    }
+}
+
+mod c_sort_should {
+    use super::*;
+
+    #[test]
+    fn keep_empty() {
+        let mut values: [i32; 0] = [];
+        c_sort(&mut values);
+        assert_eq!([0i32; 0], values);
+    }
+
+    #[test]
+    fn sort_values() {
+        let mut values = [42, -1, 1337, 0, 13];
+        c_sort(&mut values);
+        assert_eq!([-1, 0, 13, 42, 1337], values);
+    }
+}
+
+/// Optional
+/// Tree structure from 13-memory, but with raw pointers instead of `Rc`/`Weak`.
+///
+/// `Tree` owns all nodes, and `NodeMut` borrows tree so nodes can't be used after tree is dropped.
+///
+mod tree {
+    use std::marker::PhantomData;
+    use std::ptr;
+
+    struct Node<T> {
+        parent: *mut Node<T>,
+        value: T,
+        children: Vec<*mut Node<T>>,
+    }
+
+    fn new_node<T>(parent: *mut Node<T>, value: T) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node { parent, value, children: vec![] }))
+    }
+
+    /// # Safety
+    ///
+    /// `node` must have been created by `new_node` and must not be used anymore.
+    ///
+    unsafe fn free_node<T>(node: *mut Node<T>) {
+        let node = Box::from_raw(node);
+        for &child in node.children.iter() {
+            free_node(child);
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `node` must be valid, and so its children.
+    ///
+    unsafe fn collect<'a, T>(node: *const Node<T>, values: &mut Vec<&'a T>) {
+        values.push(&(*node).value);
+        for &child in (*node).children.iter() {
+            collect(child, values);
+        }
+    }
+
+    pub struct Tree<T> {
+        root: *mut Node<T>,
+    }
+
+    pub struct NodeMut<'a, T: 'a> {
+        node: *mut Node<T>,
+        tree: PhantomData<&'a mut Tree<T>>,
+    }
+
+    impl<T> Tree<T> {
+        pub fn new(value: T) -> Self {
+            Tree { root: new_node(ptr::null_mut(), value) }
+        }
+
+        pub fn root(&mut self) -> NodeMut<'_, T> {
+            NodeMut { node: self.root, tree: PhantomData }
+        }
+
+        pub fn to_vec(&self) -> Vec<&T> {
+            let mut values = vec![];
+            unsafe {
+                collect(self.root, &mut values);
+            }
+            values
+        }
+    }
+
+    impl<T> Drop for Tree<T> {
+        fn drop(&mut self) {
+            unsafe {
+                free_node(self.root);
+            }
+        }
+    }
+
+    impl<'a, T> NodeMut<'a, T> {
+        pub fn value(&self) -> &T {
+            unsafe { &(*self.node).value }
+        }
+
+        pub fn set_value(&mut self, value: T) {
+            unsafe {
+                (*self.node).value = value;
+            }
+        }
+
+        pub fn push(&mut self, value: T) -> NodeMut<'_, T> {
+            let child = new_node(self.node, value);
+            unsafe {
+                (*self.node).children.push(child);
+            }
+            NodeMut { node: child, tree: PhantomData }
+        }
+
+        pub fn parent(self) -> Option<NodeMut<'a, T>> {
+            let parent = unsafe { (*self.node).parent };
+            if parent.is_null() {
+                None
+            } else {
+                Some(NodeMut { node: parent, tree: PhantomData })
+            }
+        }
+
+        pub fn values_from_root(&self) -> Vec<&T> {
+            let mut values = vec![];
+            let mut current: *const Node<T> = self.node;
+            while !current.is_null() {
+                unsafe {
+                    values.push(&(*current).value);
+                    current = (*current).parent;
+                }
+            }
+            values.reverse();
+            values
+        }
+    }
+}
+
+mod tree_should {
+    use super::tree::Tree;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn contains_1() {
+        let tree = Tree::new("1");
+        assert_eq!(vec![&"1"], tree.to_vec());
+    }
+
+    #[test]
+    fn contains_1_1a_1a1_1b() {
+        let mut tree = Tree::new("1");
+        {
+            let mut root = tree.root();
+            root.push("1a").push("1a1");
+            root.push("1b");
+        }
+        assert_eq!(vec![&"1", &"1a", &"1a1", &"1b"], tree.to_vec());
+    }
+
+    #[test]
+    fn return_values_from_root() {
+        let mut tree = Tree::new("1");
+        let mut root = tree.root();
+        let mut t1b = root.push("1b");
+        let t1b1 = t1b.push("1b1");
+        assert_eq!(vec![&"1", &"1b", &"1b1"], t1b1.values_from_root());
+    }
+
+    #[test]
+    fn navigate_to_parent() {
+        let mut tree = Tree::new("1");
+        let mut root = tree.root();
+        let mut t1a = root.push("1a");
+        let mut parent = t1a.push("1a1").parent().unwrap();
+        parent.set_value("1A");
+        assert_eq!(&"1A", parent.value());
+        assert!(parent.parent().unwrap().parent().is_none());
+    }
+
+    struct Tracked {
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Tracked {
+        fn new(drops: &Rc<Cell<usize>>) -> Self {
+            Tracked { drops: drops.clone() }
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn not_drop_values_while_alive() {
+        let drops = Rc::new(Cell::new(0));
+        let mut tree = Tree::new(Tracked::new(&drops));
+        tree.root().push(Tracked::new(&drops)).push(Tracked::new(&drops));
+        assert_eq!(0, drops.get());
+    }
+
+    #[test]
+    fn drop_each_value_exactly_once() {
+        let drops = Rc::new(Cell::new(0));
+        {
+            let mut tree = Tree::new(Tracked::new(&drops));
+            let mut root = tree.root();
+            root.push(Tracked::new(&drops)).push(Tracked::new(&drops));
+            root.push(Tracked::new(&drops));
+        }
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn drop_replaced_value() {
+        let drops = Rc::new(Cell::new(0));
+        let mut tree = Tree::new(Tracked::new(&drops));
+        tree.root().set_value(Tracked::new(&drops));
+        assert_eq!(1, drops.get());
+    }
+}
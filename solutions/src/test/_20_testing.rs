@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+
+//!
+//! INSTRUCTIONS: Write `check_*` functions so that they pass with correct implementation and fail with each mutant (see `grader` module).
+//!
+
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use _20_testing::{is_leap_year, sort, line_count};
+
+mod mutants;
+
+#[path = "../../../common/compiler.rs"]
+mod compiler;
+
+fn check_leap_year(leap_year: fn(u32) -> bool) {
+    let years = [(2023, false), (2024, true), (1900, false), (2100, false), (2000, true), (2400, true)];
+    for &(year, expected) in years.iter() {
+        assert_eq!(expected, leap_year(year), "year: {}", year);
+    }
+}
+
+/// Checks `values` are in ascending order.
+#[track_caller]
+fn assert_sorted(values: &[i32]) {
+    for (index, pair) in values.windows(2).enumerate() {
+        assert!(pair[0] <= pair[1], "{:?} is not sorted at index {}", values, index);
+    }
+}
+
+/// Checks `actual` contains same values as `expected`, whatever their order.
+#[track_caller]
+fn assert_permutation(expected: &[i32], actual: &[i32]) {
+    let mut expected = expected.to_vec();
+    let mut actual = actual.to_vec();
+    expected.sort();
+    actual.sort();
+    assert_eq!(expected, actual, "values are not a permutation");
+}
+
+/// Very simple pseudo-random generator ([xorshift](https://en.wikipedia.org/wiki/Xorshift)).
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.next_u64() % max
+    }
+
+    fn vec_i32(&mut self, max_len: u64) -> Vec<i32> {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| self.below(200) as i32 - 100).collect()
+    }
+}
+
+/// Checks `property` holds for `cases` inputs built by `generate`. Failing input is reported into panic message.
+fn for_all<T, G, P>(cases: usize, mut generate: G, property: P) where
+    T: std::fmt::Debug + panic::RefUnwindSafe,
+    G: FnMut(&mut Rng) -> T,
+    P: Fn(&T) + panic::RefUnwindSafe {
+    let mut rng = Rng::new(0x2545_F491_4F6C_DD1D);
+    for case in 0..cases {
+        let input = generate(&mut rng);
+        if panic::catch_unwind(|| property(&input)).is_err() {
+            panic!("property failed at case {} with input: {:?}", case, input);
+        }
+    }
+}
+
+fn check_sort(sort: fn(Vec<i32>) -> Vec<i32>) {
+    assert_eq!(Vec::<i32>::new(), sort(vec![]));
+    for_all(200, |rng| rng.vec_i32(20), |values| {
+        let sorted = sort(values.clone());
+        assert_sorted(&sorted);
+        assert_permutation(values, &sorted);
+    });
+}
+
+/// File created with given content, and removed when dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn with_content(name: &str, content: &str) -> Self {
+        let path = compiler::temp_path(&format!("workshop_20_testing_{}", name));
+        fs::write(&path, content).unwrap();
+        TempFile { path }
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn check_line_count(line_count: fn(&Path) -> io::Result<usize>) {
+    let cases = [("empty", "", 0), ("single", "single line", 1), ("trailing", "a\nb\n", 2), ("no_trailing", "a\nb", 2)];
+    for &(name, content, expected) in cases.iter() {
+        let file = TempFile::with_content(name, content);
+        assert_eq!(expected, line_count(file.path()).unwrap(), "content: {:?}", content);
+    }
+    assert!(line_count(Path::new("/this/file/does/not/exist")).is_err(), "missing file");
+}
+
+mod temp_file_should {
+    use super::TempFile;
+
+    #[test]
+    fn create_file_with_content() {
+        let file = TempFile::with_content("create", "content");
+        assert_eq!("content", std::fs::read_to_string(file.path()).unwrap());
+    }
+
+    #[test]
+    fn remove_file_when_dropped() {
+        let file = TempFile::with_content("remove", "content");
+        let path = file.path().to_path_buf();
+        drop(file);
+        assert!(!path.exists());
+    }
+}
+
+mod for_all_should {
+    use super::for_all;
+
+    #[test]
+    fn pass_when_property_always_holds() {
+        for_all(100, |rng| rng.vec_i32(10), |values| assert!(values.len() <= 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "with input")]
+    fn report_input_when_property_fails() {
+        for_all(100, |rng| rng.vec_i32(10), |values| assert!(values.len() < 5));
+    }
+}
+
+mod grader {
+    use super::*;
+
+    /// Returns names of mutants which don't make `check` fail.
+    fn survivors<F: Copy + panic::RefUnwindSafe>(mutants: &[(&'static str, F)], check: fn(F)) -> Vec<&'static str> {
+        mutants.iter()
+            .filter(|&&(_, mutant)| panic::catch_unwind(|| check(mutant)).is_ok())
+            .map(|&(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn check_leap_year_should_pass_with_correct_implementation() {
+        check_leap_year(is_leap_year);
+    }
+
+    #[test]
+    fn check_leap_year_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::LEAP_YEAR, check_leap_year), "surviving mutants");
+    }
+
+    #[test]
+    fn check_sort_should_pass_with_correct_implementation() {
+        check_sort(sort);
+    }
+
+    #[test]
+    fn check_sort_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::SORT, check_sort), "surviving mutants");
+    }
+
+    #[test]
+    fn check_line_count_should_pass_with_correct_implementation() {
+        check_line_count(line_count);
+    }
+
+    #[test]
+    fn check_line_count_should_catch_all_mutants() {
+        assert_eq!(Vec::<&str>::new(), survivors(mutants::LINE_COUNT, check_line_count), "surviving mutants");
+    }
+}
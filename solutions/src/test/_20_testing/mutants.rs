@@ -0,0 +1,67 @@
+//! Buggy implementations your tests must catch. Don't read them before writing your tests !
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const LEAP_YEAR: &[(&str, fn(u32) -> bool)] = &[
+    ("every 4 years", |year| year % 4 == 0),
+    ("centuries are never leap", |year| year % 4 == 0 && year % 100 != 0),
+    ("centuries are always leap", |year| year % 4 == 0 || year % 100 == 0),
+    ("every 400 years only", |year| year % 400 == 0),
+];
+
+fn sort_and_dedup(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort();
+    values.dedup();
+    values
+}
+
+fn sort_but_last(mut values: Vec<i32>) -> Vec<i32> {
+    let last = values.pop();
+    values.sort();
+    values.extend(last);
+    values
+}
+
+fn sort_descending(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort_by(|a, b| b.cmp(a));
+    values
+}
+
+fn sort_and_truncate(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort();
+    values.truncate(8);
+    values
+}
+
+fn sort_absolute(mut values: Vec<i32>) -> Vec<i32> {
+    values.sort_by_key(|value| value.abs());
+    values
+}
+
+pub const SORT: &[(&str, fn(Vec<i32>) -> Vec<i32>)] = &[
+    ("removes duplicates", sort_and_dedup),
+    ("forgets last value", sort_but_last),
+    ("sorts descending", sort_descending),
+    ("drops values after 8th", sort_and_truncate),
+    ("compares absolute values", sort_absolute),
+];
+
+fn count_newlines(path: &Path) -> io::Result<usize> {
+    Ok(fs::read_to_string(path)?.matches('\n').count())
+}
+
+fn count_empty_as_one(path: &Path) -> io::Result<usize> {
+    Ok(fs::read_to_string(path)?.split('\n').count())
+}
+
+fn ignore_missing_file(path: &Path) -> io::Result<usize> {
+    Ok(fs::read_to_string(path).map(|content| content.lines().count()).unwrap_or(0))
+}
+
+pub const LINE_COUNT: &[(&str, fn(&Path) -> io::Result<usize>)] = &[
+    ("counts newline characters", count_newlines),
+    ("counts empty file as one line", count_empty_as_one),
+    ("ignores missing file", ignore_missing_file),
+];
@@ -0,0 +1,234 @@
+#![allow(dead_code)]
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+trait Ident: Debug {
+    fn ident(&self) -> String;
+}
+
+fn format(ident: &dyn Ident) -> String {
+    format!("[{:?}] {:?}", ident.ident(), ident)
+}
+
+#[derive(Debug)]
+struct Entity { id: u64, name: String, }
+
+impl Ident for Entity {
+    fn ident(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Debug)]
+struct Singleton;
+
+impl Ident for Singleton {
+    fn ident(&self) -> String {
+        String::from("S")
+    }
+}
+
+/// Stores any kind of `Ident` (see 06-trait).
+struct Registry {
+    items: Vec<Box<dyn Ident>>,
+}
+
+impl Registry {
+    fn new() -> Self {
+        Registry { items: vec![] }
+    }
+
+    fn register(&mut self, item: Box<dyn Ident>) {
+        self.items.push(item);
+    }
+
+    fn idents(&self) -> Vec<String> {
+        self.items.iter().map(|item| item.ident()).collect()
+    }
+
+    fn format_all(&self) -> Vec<String> {
+        self.items.iter().map(|item| format(item.as_ref())).collect()
+    }
+}
+
+mod registry_should {
+    use super::*;
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Entity { id: 42, name: String::from("an entity") }));
+        registry.register(Box::new(Singleton));
+        registry.register(Box::new(Entity { id: 314, name: String::from("Pi") }));
+        registry
+    }
+
+    #[test]
+    fn be_empty_by_default() {
+        assert!(Registry::new().idents().is_empty());
+    }
+
+    #[test]
+    fn return_idents_of_heterogeneous_items() {
+        assert_eq!(vec!["42", "S", "314"], registry().idents());
+    }
+
+    #[test]
+    fn format_heterogeneous_items() {
+        assert_eq!(vec![
+            "[\"42\"] Entity { id: 42, name: \"an entity\" }",
+            "[\"S\"] Singleton",
+            "[\"314\"] Entity { id: 314, name: \"Pi\" }",
+        ], registry().format_all());
+    }
+}
+
+mod shapes;
+
+#[path = "../../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `snippet` (as `main` body) along with `shapes.rs`.
+fn compile(name: &str, snippet: &str) -> compiler::Compilation {
+    compiler::compile(name, &compiler::program(include_str!("_21_trait_object/shapes.rs"), snippet), &[])
+}
+
+mod shape_should {
+    use super::compile;
+    use super::shapes::*;
+
+    #[test]
+    fn compute_area_of_square() {
+        assert_eq!(4.0, Square(2.0).area());
+    }
+
+    #[test]
+    fn scale_concrete_shapes() {
+        assert_eq!(36.0, Square(2.0).scaled(3.0).area());
+    }
+
+    #[test]
+    fn sum_area_of_heterogeneous_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square(2.0)), Box::new(Circle(1.0)), Box::new(Square(1.0))];
+        assert!((total_area(&shapes) - (5.0 + std::f64::consts::PI)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn compile_as_trait_object() {
+        compile("dyn_shape", "let shape: Box<dyn Shape> = Box::new(Square(1.0)); shape.area();").assert_success();
+    }
+
+    #[test]
+    fn not_compile_when_scaling_trait_object() {
+        compile("dyn_area", "let shape: Box<dyn Shape> = Box::new(Square(1.0)); shape.area();").assert_success();
+        compile("dyn_scaled", "let shape: Box<dyn Shape> = Box::new(Square(1.0)); shape.scaled(2.0);").assert_error("cannot be invoked on a trait object");
+    }
+}
+
+trait Plugin: Send {
+    fn name(&self) -> &str;
+
+    fn execute(&self, input: &str) -> String;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+struct Upper;
+
+impl Plugin for Upper {
+    fn name(&self) -> &str {
+        "upper"
+    }
+
+    fn execute(&self, input: &str) -> String {
+        input.to_uppercase()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct Repeat(usize);
+
+impl Plugin for Repeat {
+    fn name(&self) -> &str {
+        "repeat"
+    }
+
+    fn execute(&self, input: &str) -> String {
+        input.repeat(self.0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Plugins registered by name, which can be sent to another thread.
+struct PluginRegistry {
+    plugins: HashMap<String, Box<dyn Plugin>>,
+}
+
+impl PluginRegistry {
+    fn new() -> Self {
+        PluginRegistry { plugins: HashMap::new() }
+    }
+
+    fn register(&mut self, plugin: Box<dyn Plugin>) {
+        self.plugins.insert(String::from(plugin.name()), plugin);
+    }
+
+    fn execute(&self, name: &str, input: &str) -> Option<String> {
+        self.plugins.get(name).map(|plugin| plugin.execute(input))
+    }
+
+    /// Returns plugin registered as `name`, if it is a `P`.
+    fn get<P: Plugin + 'static>(&self, name: &str) -> Option<&P> {
+        self.plugins.get(name).and_then(|plugin| plugin.as_any().downcast_ref::<P>())
+    }
+}
+
+mod plugin_registry_should {
+    use super::*;
+    use std::thread;
+
+    fn registry() -> PluginRegistry {
+        let mut registry = PluginRegistry::new();
+        registry.register(Box::new(Upper));
+        registry.register(Box::new(Repeat(3)));
+        registry
+    }
+
+    #[test]
+    fn execute_plugin_by_name() {
+        let registry = registry();
+        assert_eq!(Some(String::from("RUST")), registry.execute("upper", "rust"));
+        assert_eq!(Some(String::from("rustrustrust")), registry.execute("repeat", "rust"));
+    }
+
+    #[test]
+    fn return_none_when_unknown_plugin() {
+        assert_eq!(None, registry().execute("unknown", "rust"));
+    }
+
+    #[test]
+    fn be_sent_to_another_thread() {
+        let registry = registry();
+        let result = thread::spawn(move || registry.execute("upper", "thread")).join().unwrap();
+        assert_eq!(Some(String::from("THREAD")), result);
+    }
+
+    #[test]
+    fn downcast_to_concrete_plugin() {
+        let registry = registry();
+        assert_eq!(3, registry.get::<Repeat>("repeat").unwrap().0);
+        assert!(registry.get::<Upper>("upper").is_some());
+    }
+
+    #[test]
+    fn return_none_when_downcasting_to_wrong_type() {
+        assert!(registry().get::<Upper>("repeat").is_none());
+    }
+}
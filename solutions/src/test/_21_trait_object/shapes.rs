@@ -0,0 +1,36 @@
+/// A shape which can be stored as trait object (`Box<dyn Shape>`).
+pub trait Shape {
+    fn area(&self) -> f64;
+
+    /// Returns a copy of this shape, scaled by `factor`.
+    fn scaled(&self, factor: f64) -> Self where Self: Sized;
+}
+
+pub struct Square(pub f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn scaled(&self, factor: f64) -> Self {
+        Square(self.0 * factor)
+    }
+}
+
+pub struct Circle(pub f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+
+    fn scaled(&self, factor: f64) -> Self {
+        Circle(self.0 * factor)
+    }
+}
+
+/// Sums area of all shapes.
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
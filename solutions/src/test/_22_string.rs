@@ -0,0 +1,304 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+/// Counts occurrences of each word of `text`, case-insensitively. Words are sequences of alphanumeric characters.
+fn word_frequencies(text: &str) -> HashMap<String, usize> {
+    let mut frequencies = HashMap::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()) {
+        *frequencies.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    frequencies
+}
+
+mod word_frequencies_should {
+    use super::*;
+
+    #[test]
+    fn return_empty_map_when_no_word() {
+        assert!(word_frequencies(" ,;!? ").is_empty());
+    }
+
+    #[test]
+    fn count_words_ignoring_punctuation() {
+        let frequencies = word_frequencies("one, two; two! three? three three.");
+        assert_eq!(3, frequencies.len());
+        assert_eq!(Some(&1), frequencies.get("one"));
+        assert_eq!(Some(&2), frequencies.get("two"));
+        assert_eq!(Some(&3), frequencies.get("three"));
+    }
+
+    #[test]
+    fn count_words_ignoring_case() {
+        let frequencies = word_frequencies("Rust rust RUST");
+        assert_eq!(Some(&3), frequencies.get("rust"));
+    }
+
+    #[test]
+    fn count_non_ascii_words() {
+        let frequencies = word_frequencies("Ça va ? Ça VA. Été");
+        assert_eq!(Some(&2), frequencies.get("ça"));
+        assert_eq!(Some(&2), frequencies.get("va"));
+        assert_eq!(Some(&1), frequencies.get("été"));
+    }
+}
+
+/// Parses `key = value` lines. Spaces around keys and values are ignored, as well as blank lines and comment lines (starting with `#`).
+fn parse_properties(input: &str) -> Result<Vec<(&str, &str)>, String> {
+    let mut properties = vec![];
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=');
+        let key = parts.next().unwrap().trim();
+        let value = match parts.next() {
+            Some(value) => value.trim(),
+            None => return Err(format!("line {}: missing '='", index + 1)),
+        };
+        if key.is_empty() {
+            return Err(format!("line {}: missing key", index + 1));
+        }
+        properties.push((key, value));
+    }
+    Ok(properties)
+}
+
+mod parse_properties_should {
+    use super::*;
+
+    #[test]
+    fn return_empty_when_empty_input() {
+        assert_eq!(Ok(vec![]), parse_properties(""));
+    }
+
+    #[test]
+    fn parse_trimmed_key_values() {
+        assert_eq!(
+            Ok(vec![("name", "Ferris"), ("language", "Rust")]),
+            parse_properties("name=Ferris\n  language =   Rust  ")
+        );
+    }
+
+    #[test]
+    fn ignore_blank_and_comment_lines() {
+        assert_eq!(
+            Ok(vec![("name", "Ferris")]),
+            parse_properties("# a comment\n\n   \nname = Ferris\n  # another comment")
+        );
+    }
+
+    #[test]
+    fn keep_equal_signs_into_value() {
+        assert_eq!(Ok(vec![("formula", "a = b")]), parse_properties("formula = a = b"));
+    }
+
+    #[test]
+    fn accept_empty_value() {
+        assert_eq!(Ok(vec![("empty", "")]), parse_properties("empty ="));
+    }
+
+    #[test]
+    fn return_error_when_missing_equal_sign() {
+        assert_eq!(Err(String::from("line 2: missing '='")), parse_properties("name = Ferris\nlanguage"));
+    }
+
+    #[test]
+    fn return_error_when_missing_key() {
+        assert_eq!(Err(String::from("line 1: missing key")), parse_properties(" = Ferris"));
+    }
+}
+
+/// Returns first `max_chars` characters of `text`.
+fn truncate(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => &text[..index],
+        None => text,
+    }
+}
+
+/// Returns longest prefix of `text` which is at most `max_bytes` long, without splitting any character.
+fn truncate_bytes(text: &str, max_bytes: usize) -> &str {
+    if max_bytes >= text.len() {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+mod truncate_should {
+    use super::*;
+
+    #[test]
+    fn keep_short_text() {
+        assert_eq!("rust", truncate("rust", 10));
+    }
+
+    #[test]
+    fn truncate_ascii_text() {
+        assert_eq!("ru", truncate("rust", 2));
+    }
+
+    #[test]
+    fn count_characters_instead_of_bytes() {
+        assert_eq!("été", truncate("été chaud", 3));
+        assert_eq!("🦀", truncate("🦀🦀", 1));
+    }
+
+    #[test]
+    fn keep_short_text_in_bytes() {
+        assert_eq!("rust", truncate_bytes("rust", 10));
+    }
+
+    #[test]
+    fn truncate_ascii_text_in_bytes() {
+        assert_eq!("ru", truncate_bytes("rust", 2));
+    }
+
+    #[test]
+    fn not_split_characters_in_bytes() {
+        assert_eq!("", truncate_bytes("été", 1));
+        assert_eq!("ét", truncate_bytes("été", 4));
+        assert_eq!("a", truncate_bytes("a🦀", 4));
+    }
+}
+
+/// Converts `camelCase` or `PascalCase` identifier to `snake_case`.
+fn to_snake_case(identifier: &str) -> String {
+    let mut snake = String::with_capacity(identifier.len());
+    for (index, c) in identifier.chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Converts `snake_case` identifier to `camelCase`.
+fn to_camel_case(identifier: &str) -> String {
+    let mut camel = String::with_capacity(identifier.len());
+    let mut upper_next = false;
+    for c in identifier.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            camel.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+mod case_should {
+    use super::*;
+
+    #[test]
+    fn convert_camel_case_to_snake_case() {
+        assert_eq!("insert_at_right_place", to_snake_case("insertAtRightPlace"));
+    }
+
+    #[test]
+    fn convert_pascal_case_to_snake_case() {
+        assert_eq!("hash_map", to_snake_case("HashMap"));
+    }
+
+    #[test]
+    fn convert_non_ascii_to_snake_case() {
+        assert_eq!("été_chaud", to_snake_case("ÉtéChaud"));
+    }
+
+    #[test]
+    fn convert_snake_case_to_camel_case() {
+        assert_eq!("insertAtRightPlace", to_camel_case("insert_at_right_place"));
+    }
+
+    #[test]
+    fn convert_non_ascii_to_camel_case() {
+        assert_eq!("étéÉtéChaud", to_camel_case("été_été_chaud"));
+    }
+
+    #[test]
+    fn convert_to_many_upper_case_characters() {
+        assert_eq!("großSSig", to_camel_case("groß_ßig"));
+    }
+}
+
+/// Characters of 1, 2, 3 and 4 bytes, with punctuation and upper cases.
+const ALPHABET: &[char] = &['a', 'b', 'Z', ' ', ',', 'é', 'É', 'ß', '€', '漢', '🦀'];
+
+/// Calls `check` with every text of up to 3 characters of `alphabet`, then with longer texts
+/// going through `alphabet` with each stride.
+fn for_all_texts<F: Fn(&str)>(alphabet: &[char], check: F) {
+    let mut texts = vec![String::new()];
+    let mut shortest = 0;
+    for _ in 0..3 {
+        let longer: Vec<String> = texts[shortest..].iter()
+            .flat_map(|text| alphabet.iter().map(move |c| format!("{}{}", text, c)))
+            .collect();
+        shortest = texts.len();
+        texts.extend(longer);
+    }
+    for stride in 1..alphabet.len() + 1 {
+        texts.push(alphabet.iter().cycle().step_by(stride).take(20).collect());
+    }
+    for text in &texts {
+        check(text);
+    }
+}
+
+mod properties_should {
+    use super::*;
+
+    #[test]
+    fn truncate_as_chars_take() {
+        for_all_texts(ALPHABET, |text| {
+            for max_chars in 0..22 {
+                let expected: String = text.chars().take(max_chars).collect();
+                assert_eq!(expected, truncate(text, max_chars), "truncate({:?}, {})", text, max_chars);
+            }
+        });
+    }
+
+    #[test]
+    fn truncate_bytes_to_longest_valid_prefix() {
+        for_all_texts(ALPHABET, |text| {
+            for max_bytes in 0..text.len() + 2 {
+                let expected = (0..=max_bytes.min(text.len())).rev().find_map(|end| text.get(..end)).unwrap();
+                assert_eq!(expected, truncate_bytes(text, max_bytes), "truncate_bytes({:?}, {})", text, max_bytes);
+            }
+        });
+    }
+
+    #[test]
+    fn count_as_many_words_as_split_whitespace() {
+        let alphabet = &['a', 'B', 'é', 'É', '漢', ' ', '\n'];
+        for_all_texts(alphabet, |text| {
+            let expected = text.split_whitespace().count();
+            assert_eq!(expected, word_frequencies(text).values().sum::<usize>(), "word_frequencies({:?})", text);
+        });
+    }
+
+    #[test]
+    fn convert_back_to_same_snake_case() {
+        // Only letters with a single upper case character: not 'ß' (upper case is "SS"), nor '漢' (no case at all)
+        let alphabet = &['a', 'z', 'é', 'ж', '_'];
+        for_all_texts(alphabet, |text| {
+            // Only valid snake case identifiers: no leading, trailing nor consecutive underscores
+            if text.split('_').any(|word| word.is_empty()) {
+                return;
+            }
+            assert_eq!(text, to_snake_case(&to_camel_case(text)), "round trip of {:?}", text);
+        });
+    }
+}
@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+
+use _25_cli::*;
+
+mod parse_args_should {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Config, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn count_config(lines: bool, words: bool, bytes: bool, file: Option<&str>) -> Config {
+        Config::Count { lines, words, bytes, file: file.map(String::from) }
+    }
+
+    #[test]
+    fn select_all_counts_by_default() {
+        assert_eq!(Ok(count_config(true, true, true, None)), parse(&[]));
+    }
+
+    #[test]
+    fn parse_short_and_long_options() {
+        assert_eq!(Ok(count_config(true, false, false, None)), parse(&["-l"]));
+        assert_eq!(Ok(count_config(false, true, true, None)), parse(&["--words", "-c"]));
+        assert_eq!(Ok(count_config(true, false, true, None)), parse(&["--bytes", "--lines"]));
+    }
+
+    #[test]
+    fn parse_file() {
+        assert_eq!(Ok(count_config(false, true, false, Some("notes.txt"))), parse(&["-w", "notes.txt"]));
+        assert_eq!(Ok(count_config(true, true, true, Some("notes.txt"))), parse(&["notes.txt"]));
+    }
+
+    #[test]
+    fn parse_help() {
+        assert_eq!(Ok(Config::Help), parse(&["--help"]));
+        assert_eq!(Ok(Config::Help), parse(&["-l", "-h", "--unknown"]));
+    }
+
+    #[test]
+    fn reject_unknown_option() {
+        assert_eq!(Err(String::from("unknown option '--unknown'")), parse(&["-l", "--unknown"]));
+    }
+
+    #[test]
+    fn reject_many_files() {
+        assert_eq!(Err(String::from("unexpected argument 'b.txt'")), parse(&["a.txt", "b.txt"]));
+    }
+}
+
+mod count_should {
+    use super::*;
+
+    fn count_str(input: &str) -> Counts {
+        count(input.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn count_nothing_when_empty() {
+        assert_eq!(Counts { lines: 0, words: 0, bytes: 0 }, count_str(""));
+    }
+
+    #[test]
+    fn count_lines_words_and_bytes() {
+        assert_eq!(Counts { lines: 2, words: 5, bytes: 28 }, count_str("Hello world !\n  été  rust\n"));
+    }
+
+    #[test]
+    fn count_newlines_only() {
+        assert_eq!(Counts { lines: 1, words: 3, bytes: 5 }, count_str("a\nb c"));
+    }
+}
@@ -0,0 +1,588 @@
+#![allow(dead_code)]
+
+use std::alloc::{self, Layout};
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+use std::rc::Rc;
+
+/// Records its name into `log` when dropped.
+struct Tracked {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn drop_log() -> Rc<RefCell<Vec<&'static str>>> {
+    Rc::new(RefCell::new(vec![]))
+}
+
+fn tracked(name: &'static str, log: &Rc<RefCell<Vec<&'static str>>>) -> Tracked {
+    Tracked { name, log: log.clone() }
+}
+
+/// Owns a `T` allocated on the heap.
+struct MyBox<T> {
+    ptr: NonNull<T>,
+    /// Tells compiler `MyBox` owns a `T`, and may drop it
+    owned: PhantomData<T>,
+}
+
+/// Raw pointers aren't `Send`, but `MyBox` can be sent as long as its content can.
+unsafe impl<T: Send> Send for MyBox<T> {}
+
+impl<T> MyBox<T> {
+    fn new(value: T) -> Self {
+        let layout = Layout::new::<T>();
+        let ptr = if layout.size() == 0 {
+            // Zero-sized types need no memory, but pointer must still be non-null and aligned
+            NonNull::dangling()
+        } else {
+            let raw = unsafe { alloc::alloc(layout) } as *mut T;
+            NonNull::new(raw).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+        };
+        unsafe { ptr.as_ptr().write(value) };
+        MyBox { ptr, owned: PhantomData }
+    }
+
+    /// Moves value out of heap, and frees memory.
+    fn into_inner(self) -> T {
+        let value = unsafe { ptr::read(self.ptr.as_ptr()) };
+        unsafe { deallocate(self.ptr) };
+        mem::forget(self); // Value has been moved out, it must not be dropped again
+        value
+    }
+}
+
+/// Frees memory of `ptr`, without dropping its content.
+///
+/// # Safety
+///
+/// `ptr` must have been allocated by `MyBox::new`, and not freed yet.
+unsafe fn deallocate<T>(ptr: NonNull<T>) {
+    let layout = Layout::new::<T>();
+    if layout.size() != 0 {
+        alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.ptr.as_ptr());
+            deallocate(self.ptr);
+        }
+    }
+}
+
+mod my_box_should {
+    use super::*;
+
+    fn length(text: &str) -> usize {
+        text.len()
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn give_access_to_value() {
+        let boxed = MyBox::new(String::from("rust"));
+        assert_eq!("rust", *boxed);
+        assert_eq!(4, boxed.len()); // Auto-deref
+        assert_eq!(4, length(&boxed)); // Deref coercion: &MyBox<String> -> &String -> &str
+    }
+
+    #[test]
+    fn give_mutable_access_to_value() {
+        let mut boxed = MyBox::new(vec![1, 2]);
+        boxed.push(3);
+        *boxed = vec![4];
+        assert_eq!(vec![4], *boxed);
+    }
+
+    #[test]
+    fn drop_value_once() {
+        let log = drop_log();
+        {
+            let _boxed = MyBox::new(tracked("boxed", &log));
+            assert!(log.borrow().is_empty());
+        }
+        assert_eq!(vec!["boxed"], *log.borrow());
+    }
+
+    #[test]
+    fn drop_replaced_value() {
+        let log = drop_log();
+        let mut boxed = MyBox::new(tracked("first", &log));
+        *boxed = tracked("second", &log);
+        assert_eq!(vec!["first"], *log.borrow());
+        drop(boxed);
+        assert_eq!(vec!["first", "second"], *log.borrow());
+    }
+
+    #[test]
+    fn drop_in_reverse_declaration_order() {
+        let log = drop_log();
+        {
+            let _a = MyBox::new(tracked("a", &log));
+            let _b = MyBox::new(MyBox::new(tracked("b", &log)));
+        }
+        assert_eq!(vec!["b", "a"], *log.borrow());
+    }
+
+    #[test]
+    fn not_drop_value_moved_out() {
+        let log = drop_log();
+        let value = MyBox::new(tracked("moved", &log)).into_inner();
+        assert!(log.borrow().is_empty());
+        drop(value);
+        assert_eq!(vec!["moved"], *log.borrow());
+    }
+
+    #[test]
+    fn support_zero_sized_types() {
+        let boxed = MyBox::new(());
+        assert_eq!((), *boxed);
+        assert_eq!((), boxed.into_inner());
+    }
+
+    #[test]
+    fn be_sent_to_another_thread() {
+        let boxed = MyBox::new(42);
+        assert_send(&boxed);
+        assert_eq!(42, std::thread::spawn(move || *boxed).join().unwrap());
+    }
+}
+
+struct RcBox<T> {
+    strong: Cell<usize>,
+    /// Weak references, plus one shared by all strong ones
+    weak: Cell<usize>,
+    value: ManuallyDrop<T>,
+}
+
+/// Single-threaded reference-counted pointer.
+struct MyRc<T> {
+    ptr: NonNull<RcBox<T>>,
+    owned: PhantomData<RcBox<T>>,
+}
+
+/// Non-owning reference to a `MyRc` value.
+struct MyWeak<T> {
+    ptr: NonNull<RcBox<T>>,
+}
+
+impl<T> MyRc<T> {
+    fn new(value: T) -> Self {
+        let rc_box = Box::new(RcBox { strong: Cell::new(1), weak: Cell::new(1), value: ManuallyDrop::new(value) });
+        MyRc { ptr: NonNull::from(Box::leak(rc_box)), owned: PhantomData }
+    }
+
+    fn inner(&self) -> &RcBox<T> {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    fn strong_count(this: &Self) -> usize {
+        this.inner().strong.get()
+    }
+
+    fn weak_count(this: &Self) -> usize {
+        this.inner().weak.get() - 1
+    }
+
+    fn downgrade(this: &Self) -> MyWeak<T> {
+        let inner = this.inner();
+        inner.weak.set(inner.weak.get() + 1);
+        MyWeak { ptr: this.ptr }
+    }
+
+    fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+}
+
+impl<T> Clone for MyRc<T> {
+    fn clone(&self) -> Self {
+        let inner = self.inner();
+        inner.strong.set(inner.strong.get() + 1);
+        MyRc { ptr: self.ptr, owned: PhantomData }
+    }
+}
+
+impl<T> Deref for MyRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T> Drop for MyRc<T> {
+    fn drop(&mut self) {
+        let strong = self.inner().strong.get() - 1;
+        self.inner().strong.set(strong);
+        if strong == 0 {
+            // Value may hold weak references to this box: the shared weak one keeps it alive meanwhile
+            unsafe { ManuallyDrop::drop(&mut (*self.ptr.as_ptr()).value) };
+            drop(MyWeak { ptr: self.ptr });
+        }
+    }
+}
+
+impl<T> MyWeak<T> {
+    /// Counters are reached through raw pointer, as value may be being dropped (see `MyRc::drop`)
+    fn strong(&self) -> &Cell<usize> {
+        unsafe { &(*self.ptr.as_ptr()).strong }
+    }
+
+    fn weak(&self) -> &Cell<usize> {
+        unsafe { &(*self.ptr.as_ptr()).weak }
+    }
+
+    fn upgrade(&self) -> Option<MyRc<T>> {
+        let strong = self.strong().get();
+        if strong == 0 {
+            return None;
+        }
+        self.strong().set(strong + 1);
+        Some(MyRc { ptr: self.ptr, owned: PhantomData })
+    }
+}
+
+impl<T> Clone for MyWeak<T> {
+    fn clone(&self) -> Self {
+        self.weak().set(self.weak().get() + 1);
+        MyWeak { ptr: self.ptr }
+    }
+}
+
+impl<T> Drop for MyWeak<T> {
+    fn drop(&mut self) {
+        let weak = self.weak().get() - 1;
+        self.weak().set(weak);
+        if weak == 0 {
+            // Value has already been dropped, ManuallyDrop prevents dropping it again
+            drop(unsafe { Box::from_raw(self.ptr.as_ptr()) });
+        }
+    }
+}
+
+mod my_rc_should {
+    use super::*;
+
+    #[test]
+    fn share_value() {
+        let first = MyRc::new(String::from("shared"));
+        let second = first.clone();
+        assert_eq!("shared", *second);
+        assert!(MyRc::ptr_eq(&first, &second));
+        assert!(!MyRc::ptr_eq(&first, &MyRc::new(String::from("shared"))));
+    }
+
+    #[test]
+    fn count_references() {
+        let first = MyRc::new(42);
+        assert_eq!((1, 0), (MyRc::strong_count(&first), MyRc::weak_count(&first)));
+        let second = first.clone();
+        let weak = MyRc::downgrade(&first);
+        let _weak2 = weak.clone();
+        assert_eq!((2, 2), (MyRc::strong_count(&first), MyRc::weak_count(&first)));
+        drop(second);
+        drop(weak);
+        assert_eq!((1, 1), (MyRc::strong_count(&first), MyRc::weak_count(&first)));
+    }
+
+    #[test]
+    fn drop_value_with_last_strong_reference() {
+        let log = drop_log();
+        let first = MyRc::new(tracked("shared", &log));
+        let second = first.clone();
+        drop(first);
+        assert!(log.borrow().is_empty());
+        drop(second);
+        assert_eq!(vec!["shared"], *log.borrow());
+    }
+
+    #[test]
+    fn upgrade_weak_while_value_is_alive() {
+        let log = drop_log();
+        let strong = MyRc::new(tracked("value", &log));
+        let weak = MyRc::downgrade(&strong);
+        assert_eq!("value", weak.upgrade().unwrap().name);
+        assert_eq!(1, MyRc::strong_count(&strong));
+
+        drop(strong);
+        assert_eq!(vec!["value"], *log.borrow());
+        assert!(weak.upgrade().is_none());
+    }
+
+    struct Node {
+        name: &'static str,
+        parent: RefCell<Option<MyWeak<Node>>>,
+        children: RefCell<Vec<MyRc<Node>>>,
+        tracked: Tracked,
+    }
+
+    fn node(name: &'static str, log: &Rc<RefCell<Vec<&'static str>>>) -> MyRc<Node> {
+        MyRc::new(Node { name, parent: RefCell::new(None), children: RefCell::new(vec![]), tracked: tracked(name, log) })
+    }
+
+    #[test]
+    fn not_leak_tree_with_weak_parents() {
+        let log = drop_log();
+        {
+            let root = node("root", &log);
+            for &name in ["left", "right"].iter() {
+                let child = node(name, &log);
+                *child.parent.borrow_mut() = Some(MyRc::downgrade(&root));
+                root.children.borrow_mut().push(child);
+            }
+            let left = root.children.borrow()[0].clone();
+            assert_eq!("root", left.parent.borrow().as_ref().unwrap().upgrade().unwrap().name);
+        }
+        // Fields are dropped in declaration order: root's children before its `tracked` field
+        assert_eq!(vec!["left", "right", "root"], *log.borrow());
+    }
+
+    #[test]
+    fn drop_value_holding_weak_reference_to_itself() {
+        let log = drop_log();
+        let node = node("self", &log);
+        *node.parent.borrow_mut() = Some(MyRc::downgrade(&node));
+        drop(node);
+        assert_eq!(vec!["self"], *log.borrow());
+    }
+
+    #[test]
+    fn leak_strong_cycles() {
+        let log = drop_log();
+        {
+            let first = node("first", &log);
+            let second = node("second", &log);
+            first.children.borrow_mut().push(second.clone());
+            second.children.borrow_mut().push(first.clone());
+        }
+        assert!(log.borrow().is_empty(), "strong cycles can't be freed by reference counting");
+    }
+}
+
+/// Mutable memory location with dynamically checked borrow rules.
+struct MyRefCell<T> {
+    /// Number of active shared borrows, or `-1` when mutably borrowed
+    borrow: Cell<isize>,
+    value: UnsafeCell<T>,
+}
+
+struct MyRef<'a, T: 'a> {
+    cell: &'a MyRefCell<T>,
+}
+
+struct MyRefMut<'a, T: 'a> {
+    cell: &'a MyRefCell<T>,
+}
+
+impl<T> MyRefCell<T> {
+    fn new(value: T) -> Self {
+        MyRefCell { borrow: Cell::new(0), value: UnsafeCell::new(value) }
+    }
+
+    fn try_borrow(&self) -> Option<MyRef<'_, T>> {
+        let borrow = self.borrow.get();
+        if borrow < 0 {
+            return None;
+        }
+        self.borrow.set(borrow + 1);
+        Some(MyRef { cell: self })
+    }
+
+    fn try_borrow_mut(&self) -> Option<MyRefMut<'_, T>> {
+        if self.borrow.get() != 0 {
+            return None;
+        }
+        self.borrow.set(-1);
+        Some(MyRefMut { cell: self })
+    }
+
+    fn borrow(&self) -> MyRef<'_, T> {
+        self.try_borrow().expect("RefCell already mutably borrowed")
+    }
+
+    fn borrow_mut(&self) -> MyRefMut<'_, T> {
+        self.try_borrow_mut().expect("RefCell already borrowed")
+    }
+
+    fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<'a, T> Deref for MyRef<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<'a, T> Drop for MyRef<'a, T> {
+    fn drop(&mut self) {
+        self.cell.borrow.set(self.cell.borrow.get() - 1);
+    }
+}
+
+impl<'a, T> Deref for MyRefMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.cell.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for MyRefMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.cell.value.get() }
+    }
+}
+
+impl<'a, T> Drop for MyRefMut<'a, T> {
+    fn drop(&mut self) {
+        self.cell.borrow.set(0);
+    }
+}
+
+mod my_ref_cell_should {
+    use super::*;
+    use std::panic;
+
+    /// Returns panic message of `f`, or `None` when it doesn't panic.
+    fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> Option<String> {
+        panic::catch_unwind(f).err().map(|error| match error.downcast::<String>() {
+            Ok(message) => *message,
+            Err(error) => String::from(*error.downcast::<&str>().unwrap()),
+        })
+    }
+
+    #[test]
+    fn allow_many_shared_borrows() {
+        let cell = MyRefCell::new(42);
+        let (first, second) = (cell.borrow(), cell.borrow());
+        assert_eq!(84, *first + *second);
+    }
+
+    #[test]
+    fn allow_mutation_through_shared_reference() {
+        let cell = MyRefCell::new(vec![1]);
+        let shared = &cell;
+        shared.borrow_mut().push(2);
+        assert_eq!(vec![1, 2], cell.into_inner());
+    }
+
+    #[test]
+    fn release_borrows_when_guards_are_dropped() {
+        let cell = MyRefCell::new(0);
+        {
+            let _first = cell.borrow();
+            let _second = cell.borrow();
+            assert!(cell.try_borrow_mut().is_none());
+        }
+        *cell.borrow_mut() += 1;
+        assert!(cell.try_borrow().is_some());
+        assert_eq!(1, *cell.borrow());
+    }
+
+    #[test]
+    fn refuse_borrows_while_mutably_borrowed() {
+        let cell = MyRefCell::new(0);
+        let _mutable = cell.borrow_mut();
+        assert!(cell.try_borrow().is_none());
+        assert!(cell.try_borrow_mut().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn panic_on_double_mutable_borrow() {
+        let cell = MyRefCell::new(0);
+        let _first = cell.borrow_mut();
+        let _second = cell.borrow_mut();
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn panic_on_mutable_borrow_while_borrowed() {
+        let cell = MyRefCell::new(0);
+        let _first = cell.borrow();
+        let _second = cell.borrow_mut();
+    }
+
+    #[test]
+    #[should_panic(expected = "already mutably borrowed")]
+    fn panic_on_borrow_while_mutably_borrowed() {
+        let cell = MyRefCell::new(0);
+        let _first = cell.borrow_mut();
+        let _second = cell.borrow();
+    }
+
+    #[test]
+    fn panic_in_same_situations_as_ref_cell() {
+        let scenarios: [(fn(), fn()); 3] = [
+            (|| { let c = RefCell::new(0); let _a = c.borrow_mut(); let _b = c.borrow_mut(); },
+             || { let c = MyRefCell::new(0); let _a = c.borrow_mut(); let _b = c.borrow_mut(); }),
+            (|| { let c = RefCell::new(0); let _a = c.borrow(); let _b = c.borrow_mut(); },
+             || { let c = MyRefCell::new(0); let _a = c.borrow(); let _b = c.borrow_mut(); }),
+            (|| { let c = RefCell::new(0); let _a = c.borrow(); let _b = c.borrow(); },
+             || { let c = MyRefCell::new(0); let _a = c.borrow(); let _b = c.borrow(); }),
+        ];
+        for (index, &(expected, actual)) in scenarios.iter().enumerate() {
+            assert_eq!(panic_message(expected).is_some(), panic_message(actual).is_some(), "scenario {}", index);
+        }
+    }
+
+    #[test]
+    fn drop_value_once() {
+        let log = drop_log();
+        {
+            let cell = MyRefCell::new(tracked("cell", &log));
+            let _borrowed = cell.borrow();
+        }
+        assert_eq!(vec!["cell"], *log.borrow());
+    }
+}
+
+mod together_should {
+    use super::*;
+
+    #[test]
+    fn share_mutable_state_without_leak() {
+        let log = drop_log();
+        {
+            let shared = MyRc::new(MyRefCell::new(MyBox::new(vec![tracked("first", &log)])));
+            let other = shared.clone();
+            other.borrow_mut().push(tracked("second", &log));
+            assert_eq!(2, shared.borrow().len());
+            shared.borrow_mut().remove(0);
+            assert_eq!(vec!["first"], *log.borrow());
+        }
+        assert_eq!(vec!["first", "second"], *log.borrow());
+    }
+}
@@ -0,0 +1,55 @@
+/// Built by `CarBuilder` (see 04-ownership).
+#[derive(Debug, PartialEq)]
+pub struct Car {
+    brand: String,
+    model: String,
+}
+
+impl Car {
+    pub fn brand(&self) -> &str {
+        &self.brand
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+/// Marks a missing brand or model.
+pub struct Missing;
+
+/// Brand which has been set.
+pub struct Brand(String);
+
+/// Model which has been set.
+pub struct Model(String);
+
+/// Builder whose type tells what has been set: `CarBuilder<Brand, Missing>` has a brand but no model.
+pub struct CarBuilder<B, M> {
+    brand: B,
+    model: M,
+}
+
+impl CarBuilder<Missing, Missing> {
+    pub fn new() -> Self {
+        CarBuilder { brand: Missing, model: Missing }
+    }
+}
+
+impl<M> CarBuilder<Missing, M> {
+    pub fn brand(self, brand: &str) -> CarBuilder<Brand, M> {
+        CarBuilder { brand: Brand(String::from(brand)), model: self.model }
+    }
+}
+
+impl<B> CarBuilder<B, Missing> {
+    pub fn model(self, model: &str) -> CarBuilder<B, Model> {
+        CarBuilder { brand: self.brand, model: Model(String::from(model)) }
+    }
+}
+
+impl CarBuilder<Brand, Model> {
+    pub fn build(self) -> Car {
+        Car { brand: self.brand.0, model: self.model.0 }
+    }
+}
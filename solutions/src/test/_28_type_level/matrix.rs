@@ -0,0 +1,58 @@
+use std::ops::{Add, Mul};
+
+/// Matrix of `R` rows and `C` columns, checked at compile time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T: Copy + Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn new(rows: [[T; C]; R]) -> Self {
+        Matrix { rows }
+    }
+
+    pub fn zero() -> Self {
+        Matrix { rows: [[T::default(); C]; R] }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.rows[row][col]
+    }
+
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        let mut transposed = Matrix::zero();
+        for row in 0..R {
+            for col in 0..C {
+                transposed.rows[col][row] = self.rows[row][col];
+            }
+        }
+        transposed
+    }
+}
+
+/// Only square matrices have an identity.
+impl<T: Copy + Default + From<u8>, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Self {
+        let mut identity = Matrix::zero();
+        for index in 0..N {
+            identity.rows[index][index] = T::from(1);
+        }
+        identity
+    }
+}
+
+/// `R x N` matrix can only be multiplied by a `N x C` one, giving a `R x C` matrix.
+impl<T, const R: usize, const N: usize, const C: usize> Mul<Matrix<T, N, C>> for Matrix<T, R, N> where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T> {
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, other: Matrix<T, N, C>) -> Matrix<T, R, C> {
+        let mut product = Matrix::zero();
+        for row in 0..R {
+            for col in 0..C {
+                product.rows[row][col] = (0..N).fold(T::default(), |sum, index| sum + self.rows[row][index] * other.rows[index][col]);
+            }
+        }
+        product
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+
+pub trait Unit {
+    const SYMBOL: &'static str;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Meters;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seconds;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetersPerSecond;
+
+impl Unit for Meters {
+    const SYMBOL: &'static str = "m";
+}
+
+impl Unit for Seconds {
+    const SYMBOL: &'static str = "s";
+}
+
+impl Unit for MetersPerSecond {
+    const SYMBOL: &'static str = "m/s";
+}
+
+/// Value of unit `U`. Unit only exists at compile time: a `Quantity` is just a `f64` in memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<U: Unit> {
+    value: f64,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> Quantity<U> {
+    pub fn new(value: f64) -> Self {
+        Quantity { value, unit: PhantomData }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<U: Unit> Add for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn add(self, other: Quantity<U>) -> Quantity<U> {
+        Quantity::new(self.value + other.value)
+    }
+}
+
+impl<U: Unit> Sub for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn sub(self, other: Quantity<U>) -> Quantity<U> {
+        Quantity::new(self.value - other.value)
+    }
+}
+
+impl<U: Unit> Mul<f64> for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn mul(self, factor: f64) -> Quantity<U> {
+        Quantity::new(self.value * factor)
+    }
+}
+
+impl Div<Quantity<Seconds>> for Quantity<Meters> {
+    type Output = Quantity<MetersPerSecond>;
+
+    fn div(self, duration: Quantity<Seconds>) -> Quantity<MetersPerSecond> {
+        Quantity::new(self.value / duration.value)
+    }
+}
+
+impl Mul<Quantity<Seconds>> for Quantity<MetersPerSecond> {
+    type Output = Quantity<Meters>;
+
+    fn mul(self, duration: Quantity<Seconds>) -> Quantity<Meters> {
+        Quantity::new(self.value * duration.value)
+    }
+}
+
+impl<U: Unit> fmt::Display for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.value, U::SYMBOL)
+    }
+}
@@ -0,0 +1,123 @@
+use std::mem;
+use std::thread::{self, JoinHandle};
+
+/// Finds `pattern` into `text`. Found slices borrow `text` only, so `pattern` may be dropped before them.
+pub struct Highlighter<'t, 'p> {
+    text: &'t str,
+    pattern: &'p str,
+}
+
+impl<'t, 'p> Highlighter<'t, 'p> {
+    pub fn new(text: &'t str, pattern: &'p str) -> Self {
+        Highlighter { text, pattern }
+    }
+
+    pub fn first_match(&self) -> Option<&'t str> {
+        self.text.find(self.pattern).map(|start| &self.text[start..start + self.pattern.len()])
+    }
+
+    /// Returns text before first match, or whole text when not found.
+    pub fn before_match(&self) -> &'t str {
+        match self.text.find(self.pattern) {
+            Some(start) => &self.text[..start],
+            None => self.text,
+        }
+    }
+}
+
+/// Iterates over `size` elements long slices of `slice`. Last one may be shorter.
+pub struct Chunks<'a, T: 'a> {
+    slice: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    pub fn new(slice: &'a [T], size: usize) -> Self {
+        assert!(size > 0, "chunk size must not be zero");
+        Chunks { slice, size }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (chunk, remaining) = self.slice.split_at(self.size.min(self.slice.len()));
+        self.slice = remaining;
+        Some(chunk)
+    }
+}
+
+/// Same as `Chunks`, but with mutable slices.
+pub struct ChunksMut<'a, T: 'a> {
+    slice: &'a mut [T],
+    size: usize,
+}
+
+impl<'a, T> ChunksMut<'a, T> {
+    pub fn new(slice: &'a mut [T], size: usize) -> Self {
+        assert!(size > 0, "chunk size must not be zero");
+        ChunksMut { slice, size }
+    }
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        // `self.slice` can't be moved out of `&mut self`: take it, leaving an empty slice
+        let slice = mem::take(&mut self.slice);
+        let (chunk, remaining) = slice.split_at_mut(self.size.min(slice.len()));
+        self.slice = remaining;
+        Some(chunk)
+    }
+}
+
+/// Runs `task` into another thread.
+pub fn run_in_background<F, T>(task: F) -> JoinHandle<T> where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static {
+    thread::spawn(task)
+}
+
+/// Gives up ownership of `text`, which then lives until program ends.
+pub fn leak(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+/// Counts words matching `predicate`, which is given lowercase version of each word.
+pub fn count_matching<F>(words: &[&str], predicate: F) -> usize where
+    F: for<'w> Fn(&'w str) -> bool {
+    words.iter()
+        .filter(|word| {
+            let lowercase = word.to_lowercase();
+            predicate(&lowercase)
+        })
+        .count()
+}
+
+/// Collects borrowed strings.
+pub struct Cache<'a> {
+    entries: Vec<&'a str>,
+}
+
+impl<'a> Cache<'a> {
+    pub fn new() -> Self {
+        Cache { entries: vec![] }
+    }
+
+    /// Borrows `self` only during call, not for whole `'a`.
+    pub fn insert(&mut self, entry: &'a str) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[&'a str] {
+        &self.entries
+    }
+}
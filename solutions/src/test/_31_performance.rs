@@ -0,0 +1,409 @@
+#![allow(dead_code)]
+
+use std::collections::{LinkedList, VecDeque};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Benchmark settings: `warm_up` runs are ignored, then `samples` measures are taken, each one averaging `iterations` runs.
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    warm_up: usize,
+    samples: usize,
+    iterations: usize,
+}
+
+/// Durations of one run, sorted from fastest to slowest.
+#[derive(Debug)]
+struct Stats {
+    samples: Vec<Duration>,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort();
+        Stats { samples }
+    }
+
+    fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    /// Middle sample, or mean of both middle ones when there is an even number of samples.
+    fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len() % 2 == 0 {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    /// Smallest sample greater than or equal to `percent` % of samples (nearest-rank method).
+    fn percentile(&self, percent: f64) -> Duration {
+        assert!((0.0..=100.0).contains(&percent), "invalid percentile: {}", percent);
+        let rank = (percent / 100.0 * self.samples.len() as f64).ceil() as usize;
+        self.samples[rank.max(1) - 1]
+    }
+
+    /// How many times `faster` median is faster than this one.
+    fn speedup(&self, faster: &Stats) -> f64 {
+        let nanos = |duration: Duration| duration.as_nanos().max(1) as f64;
+        nanos(self.median()) / nanos(faster.median())
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "median {:?} (p10 {:?}, p90 {:?})", self.median(), self.percentile(10.0), self.percentile(90.0))
+    }
+}
+
+/// Measures average duration of `iterations` runs of `routine`, each one on a new input built by `setup` (not measured).
+fn sample<I, R, S, F>(iterations: usize, setup: &mut S, routine: &mut F) -> Duration
+where
+    S: FnMut() -> I,
+    F: FnMut(I) -> R,
+{
+    let inputs: Vec<I> = (0..iterations).map(|_| setup()).collect();
+    let start = Instant::now();
+    for input in inputs {
+        black_box(routine(black_box(input)));
+    }
+    start.elapsed() / iterations as u32
+}
+
+/// Benchmarks `routine`, each run getting a new input built by `setup`.
+fn bench<I, R, S, F>(config: &Config, mut setup: S, mut routine: F) -> Stats
+where
+    S: FnMut() -> I,
+    F: FnMut(I) -> R,
+{
+    for _ in 0..config.warm_up {
+        black_box(routine(setup()));
+    }
+    let samples = (0..config.samples).map(|_| sample(config.iterations, &mut setup, &mut routine)).collect();
+    Stats::new(samples)
+}
+
+/// Benchmarks `slow` and `fast` on same inputs, alternating samples so that noise (other processes, CPU frequency, ...) affects both alike.
+fn compare<I, R1, R2, S, F1, F2>(config: &Config, mut setup: S, mut slow: F1, mut fast: F2) -> (Stats, Stats)
+where
+    S: FnMut() -> I,
+    F1: FnMut(I) -> R1,
+    F2: FnMut(I) -> R2,
+{
+    for _ in 0..config.warm_up {
+        black_box(slow(setup()));
+        black_box(fast(setup()));
+    }
+    let mut slow_samples = Vec::with_capacity(config.samples);
+    let mut fast_samples = Vec::with_capacity(config.samples);
+    for _ in 0..config.samples {
+        slow_samples.push(sample(config.iterations, &mut setup, &mut slow));
+        fast_samples.push(sample(config.iterations, &mut setup, &mut fast));
+    }
+    (Stats::new(slow_samples), Stats::new(fast_samples))
+}
+
+const CONFIG: Config = Config { warm_up: 1, samples: 7, iterations: 3 };
+
+/// Asserts `fast` is at least `target` times faster than `slow`.
+fn assert_speedup(slow: &Stats, fast: &Stats, target: f64) {
+    let speedup = slow.speedup(fast);
+    assert!(speedup >= target, "expected a speed-up of {}, got {:.1}\nslow: {}\nfast: {}", target, speedup, slow, fast);
+}
+
+mod stats_should {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Stats {
+        Stats::new(values.iter().map(|&value| Duration::from_millis(value)).collect())
+    }
+
+    #[test]
+    fn sort_samples() {
+        let stats = millis(&[3, 1, 2]);
+        assert_eq!(Duration::from_millis(1), stats.min());
+        assert_eq!(Duration::from_millis(3), stats.max());
+    }
+
+    #[test]
+    fn compute_median_of_odd_count() {
+        assert_eq!(Duration::from_millis(5), millis(&[9, 1, 5, 7, 2]).median());
+    }
+
+    #[test]
+    fn compute_median_of_even_count() {
+        assert_eq!(Duration::from_micros(4500), millis(&[9, 1, 5, 4]).median());
+    }
+
+    #[test]
+    fn compute_percentiles() {
+        let stats = millis(&[10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(Duration::from_millis(1), stats.percentile(0.0));
+        assert_eq!(Duration::from_millis(1), stats.percentile(10.0));
+        assert_eq!(Duration::from_millis(2), stats.percentile(15.0));
+        assert_eq!(Duration::from_millis(5), stats.percentile(50.0));
+        assert_eq!(Duration::from_millis(9), stats.percentile(90.0));
+        assert_eq!(Duration::from_millis(10), stats.percentile(100.0));
+    }
+
+    #[test]
+    fn compute_speedup_from_medians() {
+        assert_eq!(4.0, millis(&[40, 80, 1]).speedup(&millis(&[10])));
+        assert_eq!(0.5, millis(&[10]).speedup(&millis(&[20])));
+    }
+
+    #[test]
+    fn display_median_and_percentiles() {
+        assert_eq!("median 5ms (p10 1ms, p90 9ms)", millis(&[9, 8, 7, 6, 5, 4, 3, 2, 1]).to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one sample is required")]
+    fn require_samples() {
+        Stats::new(vec![]);
+    }
+}
+
+mod bench_should {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn warm_up_then_run_all_iterations_with_new_inputs() {
+        let setups = Cell::new(0);
+        let runs = Cell::new(0);
+        let config = Config { warm_up: 2, samples: 3, iterations: 4 };
+        let stats = bench(&config, || setups.set(setups.get() + 1), |()| runs.set(runs.get() + 1));
+        assert_eq!(3, stats.samples.len());
+        assert_eq!(2 + 3 * 4, setups.get());
+        assert_eq!(2 + 3 * 4, runs.get());
+    }
+
+    #[test]
+    fn not_measure_setup() {
+        let config = Config { warm_up: 0, samples: 3, iterations: 2 };
+        let stats = bench(&config, || std::thread::sleep(Duration::from_millis(20)), |()| ());
+        assert!(stats.max() < Duration::from_millis(10), "setup was measured: {}", stats);
+    }
+
+    #[test]
+    fn measure_average_of_iterations() {
+        let config = Config { warm_up: 0, samples: 3, iterations: 2 };
+        let stats = bench(&config, || (), |()| std::thread::sleep(Duration::from_millis(10)));
+        assert!(stats.min() >= Duration::from_millis(10), "too fast: {}", stats);
+        assert!(stats.median() < Duration::from_millis(20), "iterations were not averaged: {}", stats);
+    }
+
+    #[test]
+    fn compare_routines_on_same_inputs() {
+        let config = Config { warm_up: 1, samples: 5, iterations: 2 };
+        let (slow, fast) = compare(&config, || 10, |millis| std::thread::sleep(Duration::from_millis(millis)), |_| ());
+        assert_eq!(5, slow.samples.len());
+        assert_eq!(5, fast.samples.len());
+        assert_speedup(&slow, &fast, 10.0);
+    }
+}
+
+/// Inserts `val` into sorted `vec`, unless already present, scanning from start.
+fn insert_linear(vec: &mut Vec<i64>, val: i64) {
+    match vec.iter().position(|&current| current >= val) {
+        Some(index) if vec[index] == val => {},
+        Some(index) => vec.insert(index, val),
+        None => vec.push(val),
+    }
+}
+
+/// Inserts `val` into sorted `vec`, unless already present, using binary search.
+fn insert_binary(vec: &mut Vec<i64>, val: i64) {
+    if let Err(index) = vec.binary_search(&val) {
+        vec.insert(index, val);
+    }
+}
+
+/// Values mostly increasing, like timestamps of events received from network: each one is
+/// shifted by up to 30, so that some come after greater ones.
+fn nearly_sorted(count: usize) -> Vec<i64> {
+    (0..count as i64).map(|index| index * 10 + index * 17 % 30).collect()
+}
+
+fn sort_with(insert: fn(&mut Vec<i64>, i64), values: &[i64]) -> Vec<i64> {
+    let mut sorted = Vec::new();
+    for &value in values {
+        insert(&mut sorted, value);
+    }
+    sorted
+}
+
+mod insert_should {
+    use super::*;
+
+    #[test]
+    fn keep_vec_sorted_without_duplicates() {
+        let values = [5, 3, 8, 3, 1, 8, 9, 0];
+        assert_eq!(vec![0, 1, 3, 5, 8, 9], sort_with(insert_linear, &values));
+        assert_eq!(vec![0, 1, 3, 5, 8, 9], sort_with(insert_binary, &values));
+    }
+
+    #[test]
+    fn give_same_result_on_nearly_sorted_values() {
+        let values = nearly_sorted(1_000);
+        let mut expected = values.clone();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(expected, sort_with(insert_linear, &values));
+        assert_eq!(expected, sort_with(insert_binary, &values));
+    }
+
+    #[test]
+    fn be_10_times_faster_with_binary_search() {
+        let values = nearly_sorted(3_000);
+        let (linear, binary) = compare(&CONFIG, || &values[..], |values| sort_with(insert_linear, values), |values| sort_with(insert_binary, values));
+        assert_speedup(&linear, &binary, 10.0);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Job {
+    id: usize,
+    remaining: u32,
+}
+
+/// Runs `jobs` one step each in turn, until they are all done. Returns job ids in completion order.
+fn round_robin_vec(jobs: &[Job]) -> Vec<usize> {
+    let mut queue = jobs.to_vec();
+    let mut done = Vec::with_capacity(jobs.len());
+    while !queue.is_empty() {
+        let mut job = queue.remove(0);
+        job.remaining -= 1;
+        if job.remaining == 0 {
+            done.push(job.id);
+        } else {
+            queue.push(job);
+        }
+    }
+    done
+}
+
+/// Same as `round_robin_vec`, with a `VecDeque` as queue.
+fn round_robin_deque(jobs: &[Job]) -> Vec<usize> {
+    let mut queue: VecDeque<Job> = jobs.iter().cloned().collect();
+    let mut done = Vec::with_capacity(jobs.len());
+    while let Some(mut job) = queue.pop_front() {
+        job.remaining -= 1;
+        if job.remaining == 0 {
+            done.push(job.id);
+        } else {
+            queue.push_back(job);
+        }
+    }
+    done
+}
+
+/// Same as `round_robin_vec`, with a `LinkedList` as queue.
+fn round_robin_list(jobs: &[Job]) -> Vec<usize> {
+    let mut queue: LinkedList<Job> = jobs.iter().cloned().collect();
+    let mut done = Vec::with_capacity(jobs.len());
+    while let Some(mut job) = queue.pop_front() {
+        job.remaining -= 1;
+        if job.remaining == 0 {
+            done.push(job.id);
+        } else {
+            queue.push_back(job);
+        }
+    }
+    done
+}
+
+fn jobs(count: usize) -> Vec<Job> {
+    (0..count).map(|id| Job { id, remaining: 1 + (id % 3) as u32 }).collect()
+}
+
+mod round_robin_should {
+    use super::*;
+
+    #[test]
+    fn complete_shortest_jobs_first() {
+        let jobs = [Job { id: 0, remaining: 3 }, Job { id: 1, remaining: 1 }, Job { id: 2, remaining: 2 }, Job { id: 3, remaining: 1 }];
+        assert_eq!(vec![1, 3, 2, 0], round_robin_vec(&jobs));
+        assert_eq!(vec![1, 3, 2, 0], round_robin_deque(&jobs));
+        assert_eq!(vec![1, 3, 2, 0], round_robin_list(&jobs));
+    }
+
+    #[test]
+    fn give_same_result_with_all_collections() {
+        let jobs = jobs(1_000);
+        let expected = round_robin_vec(&jobs);
+        assert_eq!(1_000, expected.len());
+        assert_eq!(expected, round_robin_deque(&jobs));
+        assert_eq!(expected, round_robin_list(&jobs));
+    }
+
+    #[test]
+    fn be_5_times_faster_with_vec_deque_than_vec() {
+        let jobs = jobs(5_000);
+        let (vec, deque) = compare(&CONFIG, || &jobs[..], round_robin_vec, round_robin_deque);
+        assert_speedup(&vec, &deque, 5.0);
+    }
+
+    #[test]
+    fn be_faster_with_vec_deque_than_linked_list() {
+        let jobs = jobs(5_000);
+        let (list, deque) = compare(&CONFIG, || &jobs[..], round_robin_list, round_robin_deque);
+        assert_speedup(&list, &deque, 1.5);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+    city: String,
+    tags: Vec<String>,
+}
+
+/// Returns users living in `city`, cloning them.
+fn users_in_cloning(users: &[User], city: &str) -> Vec<User> {
+    users.iter().cloned().filter(|user| user.city == city).collect()
+}
+
+/// Returns users living in `city`, borrowing them.
+fn users_in<'a>(users: &'a [User], city: &str) -> Vec<&'a User> {
+    users.iter().filter(|user| user.city == city).collect()
+}
+
+fn users(count: usize) -> Vec<User> {
+    let cities = ["Paris", "Nantes", "Lyon", "Lille"];
+    (0..count)
+        .map(|index| User {
+            name: format!("user-{}", index),
+            city: String::from(cities[index % cities.len()]),
+            tags: (0..3).map(|tag| format!("tag-{}", tag)).collect(),
+        })
+        .collect()
+}
+
+mod users_in_should {
+    use super::*;
+
+    #[test]
+    fn give_same_users() {
+        let users = users(100);
+        let cloned = users_in_cloning(&users, "Nantes");
+        assert!(!cloned.is_empty());
+        assert_eq!(cloned.iter().collect::<Vec<_>>(), users_in(&users, "Nantes"));
+    }
+
+    #[test]
+    fn be_5_times_faster_when_borrowing() {
+        let users = users(2_000);
+        let (cloning, borrowing) = compare(&CONFIG, || &users[..], |users| users_in_cloning(users, "Lyon"), |users| users_in(users, "Lyon"));
+        assert_speedup(&cloning, &borrowing, 5.0);
+    }
+}
@@ -0,0 +1,219 @@
+#![allow(dead_code)]
+
+mod ring_buffer;
+mod parser;
+
+#[path = "../../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `source` as a library, with extra `rustc` arguments.
+fn build(name: &str, source: &str, args: &[&str]) -> compiler::Compilation {
+    let args: Vec<&str> = Some("--crate-type=lib").into_iter().chain(args.iter().cloned()).collect();
+    compiler::compile(name, source, &args)
+}
+
+const CRATE: &str = concat!(
+    "#![no_std]\n",
+    "#[cfg(feature = \"alloc\")]\n",
+    "extern crate alloc;\n",
+    "pub mod ring_buffer {\n", include_str!("_32_no_std/ring_buffer.rs"), "}\n",
+    "pub mod parser {\n", include_str!("_32_no_std/parser.rs"), "}\n",
+);
+
+mod no_std_should {
+    use super::*;
+
+    #[test]
+    fn reject_std() {
+        build("core_only", "#![no_std]\npub fn name() -> &'static str { \"\" }", &[]).assert_success();
+        build("reject", "#![no_std]\npub fn name() -> std::string::String { std::string::String::new() }", &[])
+            .assert_error("E0433");
+    }
+
+    #[test]
+    fn build_without_std() {
+        build("core", CRATE, &[]).assert_success();
+    }
+
+    #[test]
+    fn build_with_alloc() {
+        build("alloc", CRATE, &["--cfg", "feature=\"alloc\""]).assert_success();
+    }
+}
+
+mod ring_buffer_should {
+    use super::ring_buffer::*;
+
+    #[test]
+    fn be_empty_when_created() {
+        let buffer: RingBuffer<u8, 4> = RingBuffer::new();
+        assert_eq!(4, buffer.capacity());
+        assert_eq!(0, buffer.len());
+        assert!(buffer.is_empty());
+        assert!(!buffer.is_full());
+        assert_eq!(None, buffer.peek());
+    }
+
+    #[test]
+    fn pop_items_in_push_order() {
+        let mut buffer: RingBuffer<&str, 3> = RingBuffer::new();
+        assert_eq!(Ok(()), buffer.push("a"));
+        assert_eq!(Ok(()), buffer.push("b"));
+        assert_eq!(Some(&"a"), buffer.peek());
+        assert_eq!(2, buffer.len());
+        assert_eq!(Some("a"), buffer.pop());
+        assert_eq!(Some("b"), buffer.pop());
+        assert_eq!(None, buffer.pop());
+    }
+
+    #[test]
+    fn reject_item_when_full() {
+        let mut buffer: RingBuffer<u8, 2> = RingBuffer::new();
+        buffer.push(1).unwrap();
+        buffer.push(2).unwrap();
+        assert!(buffer.is_full());
+        assert_eq!(Err(3), buffer.push(3));
+        assert_eq!(Some(1), buffer.pop());
+        assert_eq!(Ok(()), buffer.push(3));
+    }
+
+    #[test]
+    fn wrap_around_many_times() {
+        let mut buffer: RingBuffer<u32, 3> = RingBuffer::new();
+        for value in 0..100 {
+            buffer.push(value).unwrap();
+            if value % 2 == 1 {
+                assert_eq!(Some(value - 1), buffer.pop());
+                assert_eq!(Some(value), buffer.pop());
+            }
+        }
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn overwrite_oldest_item_when_full() {
+        let mut buffer: RingBuffer<u8, 3> = RingBuffer::new();
+        assert_eq!(None, buffer.push_overwrite(1));
+        assert_eq!(None, buffer.push_overwrite(2));
+        assert_eq!(None, buffer.push_overwrite(3));
+        assert_eq!(Some(1), buffer.push_overwrite(4));
+        assert_eq!(Some(2), buffer.push_overwrite(5));
+        assert_eq!(3, buffer.len());
+        assert_eq!(vec![3, 4, 5], buffer.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterate_from_oldest_to_newest() {
+        let mut buffer: RingBuffer<char, 4> = RingBuffer::new();
+        for letter in "abcdef".chars() {
+            buffer.push_overwrite(letter);
+        }
+        buffer.pop();
+        assert_eq!("def", buffer.iter().collect::<String>());
+        assert_eq!(3, buffer.len());
+    }
+
+    #[test]
+    fn handle_zero_capacity() {
+        let mut buffer: RingBuffer<u8, 0> = RingBuffer::default();
+        assert!(buffer.is_full());
+        assert_eq!(Err(1), buffer.push(1));
+        assert_eq!(Some(2), buffer.push_overwrite(2));
+        assert_eq!(None, buffer.pop());
+        assert_eq!(0, buffer.iter().count());
+    }
+
+    #[test]
+    fn drop_remaining_items() {
+        use std::rc::Rc;
+
+        let item = Rc::new(42);
+        {
+            let mut buffer: RingBuffer<Rc<i32>, 2> = RingBuffer::new();
+            buffer.push(Rc::clone(&item)).unwrap();
+            buffer.push_overwrite(Rc::clone(&item));
+            buffer.push_overwrite(Rc::clone(&item));
+            assert_eq!(3, Rc::strong_count(&item));
+        }
+        assert_eq!(1, Rc::strong_count(&item));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn copy_items_into_vec() {
+        let mut buffer: RingBuffer<u8, 3> = RingBuffer::new();
+        for value in 1..=5 {
+            buffer.push_overwrite(value);
+        }
+        assert_eq!(vec![3, 4, 5], buffer.to_vec());
+    }
+}
+
+mod parser_should {
+    use super::parser::*;
+
+    #[test]
+    fn parse_decimal_numbers() {
+        assert_eq!(Ok(0), parse_number(b"0"));
+        assert_eq!(Ok(42), parse_number(b"42"));
+        assert_eq!(Ok(4_294_967_295), parse_number(b"4294967295"));
+    }
+
+    #[test]
+    fn parse_hexadecimal_numbers() {
+        assert_eq!(Ok(0x2A), parse_number(b"0x2A"));
+        assert_eq!(Ok(0xbeef), parse_number(b"0xbeef"));
+    }
+
+    #[test]
+    fn reject_invalid_numbers() {
+        assert_eq!(Err(ParseError::InvalidDigit), parse_number(b""));
+        assert_eq!(Err(ParseError::InvalidDigit), parse_number(b"0x"));
+        assert_eq!(Err(ParseError::InvalidDigit), parse_number(b"12a"));
+        assert_eq!(Err(ParseError::InvalidDigit), parse_number(b"0xG1"));
+        assert_eq!(Err(ParseError::InvalidDigit), parse_number(b"-1"));
+        assert_eq!(Err(ParseError::Overflow), parse_number(b"4294967296"));
+        assert_eq!(Err(ParseError::Overflow), parse_number(b"0x100000000"));
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(Ok(Command::Reset), parse_command(b"RESET"));
+        assert_eq!(Ok(Command::Get { register: 12 }), parse_command(b"GET 12"));
+        assert_eq!(Ok(Command::Set { register: 0x1F, value: 65535 }), parse_command(b"  SET 0x1F   65535 "));
+    }
+
+    #[test]
+    fn report_command_errors() {
+        assert_eq!(Err(ParseError::Empty), parse_command(b"   "));
+        assert_eq!(Err(ParseError::UnknownCommand), parse_command(b"reset"));
+        assert_eq!(Err(ParseError::MissingArgument), parse_command(b"SET 1"));
+        assert_eq!(Err(ParseError::TooManyArguments), parse_command(b"RESET now"));
+        assert_eq!(Err(ParseError::Overflow), parse_command(b"GET 256"));
+        assert_eq!(Err(ParseError::Overflow), parse_command(b"SET 1 65536"));
+        assert_eq!(Err(ParseError::InvalidDigit), parse_command(b"GET one"));
+    }
+
+    #[test]
+    fn describe_errors() {
+        assert_eq!("unknown command", ParseError::UnknownCommand.message());
+        assert_eq!("number too large", ParseError::Overflow.message());
+    }
+
+    #[test]
+    fn iterate_over_lines() {
+        let mut commands = commands(b"GET 1\r\n\nSET 2 3\n  \nBOOM\nRESET");
+        assert_eq!(Some(Ok(Command::Get { register: 1 })), commands.next());
+        assert_eq!(Some(Ok(Command::Set { register: 2, value: 3 })), commands.next());
+        assert_eq!(Some(Err(ParseError::UnknownCommand)), commands.next());
+        assert_eq!(Some(Ok(Command::Reset)), commands.next());
+        assert_eq!(None, commands.next());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn parse_all_commands() {
+        assert_eq!(Ok(vec![Command::Reset, Command::Get { register: 7 }]), parse_all(b"RESET\nGET 7\n"));
+        assert_eq!(Err(ParseError::MissingArgument), parse_all(b"RESET\nGET\nBOOM\n"));
+    }
+}
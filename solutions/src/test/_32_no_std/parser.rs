@@ -0,0 +1,99 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Command received by a device, one per line: `RESET`, `GET <register>` or `SET <register> <value>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Reset,
+    Get { register: u8 },
+    Set { register: u8, value: u16 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand,
+    MissingArgument,
+    TooManyArguments,
+    InvalidDigit,
+    Overflow,
+}
+
+impl ParseError {
+    /// Describes error without `core::fmt` machinery, which is costly for small devices.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            ParseError::Empty => "empty command",
+            ParseError::UnknownCommand => "unknown command",
+            ParseError::MissingArgument => "missing argument",
+            ParseError::TooManyArguments => "too many arguments",
+            ParseError::InvalidDigit => "invalid digit",
+            ParseError::Overflow => "number too large",
+        }
+    }
+}
+
+/// Parses a decimal number, or a hexadecimal one when prefixed by `0x`.
+pub fn parse_number(bytes: &[u8]) -> Result<u32, ParseError> {
+    let (digits, radix) = match bytes {
+        [b'0', b'x', rest @ ..] => (rest, 16),
+        _ => (bytes, 10),
+    };
+    if digits.is_empty() {
+        return Err(ParseError::InvalidDigit);
+    }
+    let mut number: u32 = 0;
+    for &byte in digits {
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            b'A'..=b'F' => byte - b'A' + 10,
+            _ => return Err(ParseError::InvalidDigit),
+        } as u32;
+        if digit >= radix {
+            return Err(ParseError::InvalidDigit);
+        }
+        number = number.checked_mul(radix).and_then(|number| number.checked_add(digit)).ok_or(ParseError::Overflow)?;
+    }
+    Ok(number)
+}
+
+fn argument<'a, I: Iterator<Item = &'a [u8]>>(words: &mut I) -> Result<u32, ParseError> {
+    parse_number(words.next().ok_or(ParseError::MissingArgument)?)
+}
+
+/// Parses a single command. Words are separated by one or more spaces.
+pub fn parse_command(line: &[u8]) -> Result<Command, ParseError> {
+    let mut words = line.split(|&byte| byte == b' ').filter(|word| !word.is_empty());
+    let command = match words.next().ok_or(ParseError::Empty)? {
+        b"RESET" => Command::Reset,
+        b"GET" => Command::Get {
+            register: u8::try_from(argument(&mut words)?).map_err(|_| ParseError::Overflow)?,
+        },
+        b"SET" => Command::Set {
+            register: u8::try_from(argument(&mut words)?).map_err(|_| ParseError::Overflow)?,
+            value: u16::try_from(argument(&mut words)?).map_err(|_| ParseError::Overflow)?,
+        },
+        _ => return Err(ParseError::UnknownCommand),
+    };
+    match words.next() {
+        Some(_) => Err(ParseError::TooManyArguments),
+        None => Ok(command),
+    }
+}
+
+/// Parses each non-blank line of `input`. Lines may end with `\n` or `\r\n`.
+pub fn commands<'a>(input: &'a [u8]) -> impl Iterator<Item = Result<Command, ParseError>> + 'a {
+    input
+        .split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| line.iter().any(|&byte| byte != b' '))
+        .map(parse_command)
+}
+
+/// Parses all commands of `input`, stopping at first error.
+#[cfg(feature = "alloc")]
+pub fn parse_all(input: &[u8]) -> Result<Vec<Command>, ParseError> {
+    commands(input).collect()
+}
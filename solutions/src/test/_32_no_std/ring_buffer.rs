@@ -0,0 +1,113 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// First-in first-out queue of at most `N` items, stored inline (no heap allocation).
+#[derive(Debug)]
+pub struct RingBuffer<T, const N: usize> {
+    items: [Option<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub fn new() -> Self {
+        RingBuffer { items: core::array::from_fn(|_| None), head: 0, len: 0 }
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds `item` at the end, or gives it back when buffer is full.
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        let tail = (self.head + self.len) % N;
+        self.items[tail] = Some(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Adds `item` at the end. When buffer is full, oldest item is removed to make room, and returned.
+    pub fn push_overwrite(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            return Some(item);
+        }
+        if self.is_full() {
+            let oldest = self.items[self.head].replace(item);
+            self.head = (self.head + 1) % N;
+            oldest
+        } else {
+            self.push(item).ok();
+            None
+        }
+    }
+
+    /// Removes oldest item.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.items[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        item
+    }
+
+    /// Returns oldest item, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.items[self.head].as_ref()
+        }
+    }
+
+    /// Iterates from oldest to newest item.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter { buffer: self, index: 0 }
+    }
+
+    /// Copies items, from oldest to newest, into a `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        RingBuffer::new()
+    }
+}
+
+pub struct Iter<'a, T: 'a, const N: usize> {
+    buffer: &'a RingBuffer<T, N>,
+    index: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index >= self.buffer.len {
+            return None;
+        }
+        let item = self.buffer.items[(self.buffer.head + self.index) % N].as_ref();
+        self.index += 1;
+        item
+    }
+}
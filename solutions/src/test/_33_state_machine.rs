@@ -0,0 +1,255 @@
+#![allow(dead_code)]
+
+mod vending;
+mod type_state;
+
+#[path = "../../../common/compiler.rs"]
+mod compiler;
+
+/// Checks `snippet` (as `main` body) along with both machines.
+fn compile(name: &str, snippet: &str) -> compiler::Compilation {
+    compiler::compile(name, &compiler::program(MACHINES, snippet), &[])
+}
+
+const MACHINES: &str = concat!(
+    "mod vending {\n", include_str!("_33_state_machine/vending.rs"), "}\n",
+    "mod type_state {\n", include_str!("_33_state_machine/type_state.rs"), "}\n",
+    "use vending::*;\n",
+    "use type_state::*;\n",
+);
+
+mod transition_should {
+    use super::vending::*;
+
+    #[test]
+    fn collect_coins() {
+        let state = transition(State::Idle, Event::Insert(Coin::Fifty)).unwrap();
+        assert_eq!(State::Collecting { credit: 50 }, state);
+        assert_eq!(Some(State::Collecting { credit: 70 }), transition(state, Event::Insert(Coin::Twenty)));
+    }
+
+    #[test]
+    fn dispense_when_credit_is_enough() {
+        let collecting = State::Collecting { credit: 200 };
+        assert_eq!(Some(State::Dispensing { product: Product::Soda, change: 50 }), transition(collecting, Event::Select(Product::Soda)));
+        assert_eq!(Some(State::Dispensing { product: Product::Water, change: 0 }), transition(State::Collecting { credit: 80 }, Event::Select(Product::Water)));
+    }
+
+    #[test]
+    fn reject_selection_when_credit_is_too_low() {
+        assert_eq!(None, transition(State::Collecting { credit: 100 }, Event::Select(Product::Chips)));
+        assert_eq!(None, transition(State::Idle, Event::Select(Product::Water)));
+    }
+
+    #[test]
+    fn refund_when_cancelled() {
+        assert_eq!(Some(State::Refunding { amount: 30 }), transition(State::Collecting { credit: 30 }, Event::Cancel));
+        assert_eq!(None, transition(State::Idle, Event::Cancel));
+    }
+
+    #[test]
+    fn go_back_to_idle_when_taken() {
+        assert_eq!(Some(State::Idle), transition(State::Dispensing { product: Product::Chips, change: 10 }, Event::Take));
+        assert_eq!(Some(State::Idle), transition(State::Refunding { amount: 10 }, Event::Take));
+        assert_eq!(None, transition(State::Idle, Event::Take));
+        assert_eq!(None, transition(State::Collecting { credit: 10 }, Event::Take));
+    }
+
+    #[test]
+    fn reject_coins_while_busy() {
+        assert_eq!(None, transition(State::Dispensing { product: Product::Chips, change: 0 }, Event::Insert(Coin::Ten)));
+        assert_eq!(None, transition(State::Refunding { amount: 10 }, Event::Insert(Coin::Ten)));
+    }
+
+    #[test]
+    fn break_down_from_any_operational_state() {
+        for &state in &[State::Idle, State::Collecting { credit: 10 }, State::Dispensing { product: Product::Water, change: 0 }, State::Refunding { amount: 10 }] {
+            assert_eq!(Some(State::OutOfOrder), transition(state, Event::Fault), "from {:?}", state);
+        }
+    }
+
+    #[test]
+    fn only_accept_repair_when_out_of_order() {
+        assert_eq!(Some(State::Idle), transition(State::OutOfOrder, Event::Repair));
+        for &event in &[Event::Insert(Coin::Euro), Event::Select(Product::Water), Event::Cancel, Event::Take, Event::Fault] {
+            assert_eq!(None, transition(State::OutOfOrder, event), "on {:?}", event);
+        }
+        assert_eq!(None, transition(State::Idle, Event::Repair));
+    }
+}
+
+mod vending_machine_should {
+    use super::vending::*;
+
+    #[test]
+    fn start_idle() {
+        let machine = VendingMachine::new();
+        assert_eq!(State::Idle, machine.state());
+        assert_eq!(&[State::Idle], machine.trace());
+    }
+
+    #[test]
+    fn trace_a_purchase() {
+        let (trace, rejected) = replay(&[Event::Insert(Coin::Euro), Event::Insert(Coin::Twenty), Event::Insert(Coin::Ten), Event::Select(Product::Chips), Event::Take]);
+        assert_eq!(
+            vec![
+                State::Idle,
+                State::Collecting { credit: 100 },
+                State::Collecting { credit: 120 },
+                State::Collecting { credit: 130 },
+                State::Dispensing { product: Product::Chips, change: 10 },
+                State::Idle,
+            ],
+            trace
+        );
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn trace_a_cancellation() {
+        let (trace, _) = replay(&[Event::Insert(Coin::Fifty), Event::Cancel, Event::Take]);
+        assert_eq!(vec![State::Idle, State::Collecting { credit: 50 }, State::Refunding { amount: 50 }, State::Idle], trace);
+    }
+
+    #[test]
+    fn skip_rejected_events() {
+        let (trace, rejected) = replay(&[
+            Event::Take,
+            Event::Insert(Coin::Fifty),
+            Event::Select(Product::Water),
+            Event::Insert(Coin::Fifty),
+            Event::Select(Product::Water),
+            Event::Insert(Coin::Ten),
+            Event::Take,
+        ]);
+        assert_eq!(
+            vec![
+                State::Idle,
+                State::Collecting { credit: 50 },
+                State::Collecting { credit: 100 },
+                State::Dispensing { product: Product::Water, change: 20 },
+                State::Idle,
+            ],
+            trace
+        );
+        assert_eq!(
+            vec![
+                Rejected { state: State::Idle, event: Event::Take },
+                Rejected { state: State::Collecting { credit: 50 }, event: Event::Select(Product::Water) },
+                Rejected { state: State::Dispensing { product: Product::Water, change: 20 }, event: Event::Insert(Coin::Ten) },
+            ],
+            rejected
+        );
+    }
+
+    #[test]
+    fn trace_a_breakdown() {
+        let (trace, rejected) = replay(&[Event::Insert(Coin::Twenty), Event::Fault, Event::Insert(Coin::Ten), Event::Repair, Event::Insert(Coin::Ten)]);
+        assert_eq!(vec![State::Idle, State::Collecting { credit: 20 }, State::OutOfOrder, State::Idle, State::Collecting { credit: 10 }], trace);
+        assert_eq!(vec![Rejected { state: State::OutOfOrder, event: Event::Insert(Coin::Ten) }], rejected);
+    }
+
+    #[test]
+    fn return_rejection_when_handling_event() {
+        let mut machine = VendingMachine::new();
+        assert_eq!(Ok(State::Collecting { credit: 10 }), machine.handle(Event::Insert(Coin::Ten)));
+        assert_eq!(Err(Rejected { state: State::Collecting { credit: 10 }, event: Event::Repair }), machine.handle(Event::Repair));
+        assert_eq!(State::Collecting { credit: 10 }, machine.state());
+        assert_eq!(2, machine.trace().len());
+    }
+}
+
+mod type_state_should {
+    use super::compile;
+    use super::type_state::*;
+    use super::vending::*;
+
+    #[test]
+    fn trace_a_purchase() {
+        let machine = Machine::new().insert(Coin::Euro).insert(Coin::Fifty);
+        let dispensing = match machine.select(Product::Chips) {
+            Ok(dispensing) => dispensing,
+            Err(_) => panic!("credit should be enough"),
+        };
+        let (machine, product, change) = dispensing.take();
+        assert_eq!(Product::Chips, product);
+        assert_eq!(30, change);
+        assert_eq!(State::Idle, machine.state());
+        assert_eq!(
+            &[
+                State::Idle,
+                State::Collecting { credit: 100 },
+                State::Collecting { credit: 150 },
+                State::Dispensing { product: Product::Chips, change: 30 },
+                State::Idle,
+            ],
+            machine.trace()
+        );
+    }
+
+    #[test]
+    fn give_machine_back_when_credit_is_too_low() {
+        let machine = match Machine::new().insert(Coin::Fifty).select(Product::Water) {
+            Ok(_) => panic!("credit should be too low"),
+            Err(machine) => machine,
+        };
+        assert_eq!(State::Collecting { credit: 50 }, machine.state());
+        let (machine, amount) = machine.cancel().take();
+        assert_eq!(50, amount);
+        assert_eq!(&[State::Idle, State::Collecting { credit: 50 }, State::Refunding { amount: 50 }, State::Idle], machine.trace());
+    }
+
+    #[test]
+    fn trace_same_states_as_enum_machine() {
+        let machine = Machine::new().insert(Coin::Twenty).fault().repair().insert(Coin::Euro);
+        let machine = machine.select(Product::Water).ok().unwrap().fault().repair();
+
+        let (trace, rejected) = replay(&[
+            Event::Insert(Coin::Twenty),
+            Event::Fault,
+            Event::Repair,
+            Event::Insert(Coin::Euro),
+            Event::Select(Product::Water),
+            Event::Fault,
+            Event::Repair,
+        ]);
+        assert!(rejected.is_empty());
+        assert_eq!(&trace[..], machine.trace());
+    }
+
+    #[test]
+    fn compile_allowed_transitions() {
+        compile("allowed", "let machine: Machine<Idle> = Machine::new().insert(Coin::Ten).cancel().take().0.fault().repair();").assert_success();
+    }
+
+    #[test]
+    fn not_compile_selection_without_coin() {
+        compile("select_collecting", "let result = Machine::new().insert(Coin::Euro).select(Product::Water);").assert_success();
+        compile("select_idle", "let result = Machine::new().select(Product::Water);").assert_error("E0599");
+    }
+
+    #[test]
+    fn not_compile_take_while_collecting() {
+        compile("take_refunding", "let result = Machine::new().insert(Coin::Euro).cancel().take();").assert_success();
+        compile("take_collecting", "let result = Machine::new().insert(Coin::Euro).take();").assert_error("E0599");
+    }
+
+    #[test]
+    fn not_compile_coin_while_out_of_order() {
+        compile("insert_repaired", "let machine = Machine::new().fault().repair().insert(Coin::Ten);").assert_success();
+        compile("insert_out_of_order", "let machine = Machine::new().fault().insert(Coin::Ten);").assert_error("E0599");
+        compile("fault_repaired", "let machine = Machine::new().fault().repair().fault();").assert_success();
+        compile("fault_twice", "let machine = Machine::new().fault().fault();").assert_error("E0599");
+    }
+
+    #[test]
+    fn not_compile_reuse_of_previous_state() {
+        compile("chain", "let idle = Machine::new(); let collecting = idle.insert(Coin::Ten); let again = collecting.insert(Coin::Ten);").assert_success();
+        compile("reuse", "let idle = Machine::new(); let collecting = idle.insert(Coin::Ten); let again = idle.insert(Coin::Ten);").assert_error("E0382");
+    }
+
+    #[test]
+    fn not_compile_forged_credit() {
+        compile("forge", "let state = Collecting { credit: 1000 };").assert_error("E0451");
+    }
+}
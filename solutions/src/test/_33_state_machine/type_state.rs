@@ -0,0 +1,147 @@
+use super::vending::{Coin, Product, State};
+
+/// Waiting for a coin.
+pub struct Idle;
+
+/// Coins have been inserted.
+pub struct Collecting {
+    credit: u32,
+}
+
+/// Product (and change) is waiting to be taken.
+pub struct Dispensing {
+    product: Product,
+    change: u32,
+}
+
+/// Credit is waiting to be taken back.
+pub struct Refunding {
+    amount: u32,
+}
+
+/// Waiting for a technician.
+pub struct OutOfOrder;
+
+/// Gives runtime equivalent of a state, to be traced.
+pub trait Snapshot {
+    fn snapshot(&self) -> State;
+}
+
+impl Snapshot for Idle {
+    fn snapshot(&self) -> State {
+        State::Idle
+    }
+}
+
+impl Snapshot for Collecting {
+    fn snapshot(&self) -> State {
+        State::Collecting { credit: self.credit }
+    }
+}
+
+impl Snapshot for Dispensing {
+    fn snapshot(&self) -> State {
+        State::Dispensing { product: self.product, change: self.change }
+    }
+}
+
+impl Snapshot for Refunding {
+    fn snapshot(&self) -> State {
+        State::Refunding { amount: self.amount }
+    }
+}
+
+impl Snapshot for OutOfOrder {
+    fn snapshot(&self) -> State {
+        State::OutOfOrder
+    }
+}
+
+/// Marks states in which machine may break down.
+pub trait Operational: Snapshot {}
+
+impl Operational for Idle {}
+impl Operational for Collecting {}
+impl Operational for Dispensing {}
+impl Operational for Refunding {}
+
+/// Vending machine whose type tells its state: only allowed transitions are available.
+pub struct Machine<S> {
+    state: S,
+    trace: Vec<State>,
+}
+
+impl<S: Snapshot> Machine<S> {
+    /// Moves to `next` state, consuming current one.
+    fn into_state<T: Snapshot>(mut self, next: T) -> Machine<T> {
+        self.trace.push(next.snapshot());
+        Machine { state: next, trace: self.trace }
+    }
+
+    pub fn state(&self) -> State {
+        self.state.snapshot()
+    }
+
+    /// States visited so far, starting with initial one.
+    pub fn trace(&self) -> &[State] {
+        &self.trace
+    }
+}
+
+impl Machine<Idle> {
+    pub fn new() -> Self {
+        Machine { state: Idle, trace: vec![State::Idle] }
+    }
+
+    pub fn insert(self, coin: Coin) -> Machine<Collecting> {
+        self.into_state(Collecting { credit: coin.cents() })
+    }
+}
+
+impl Machine<Collecting> {
+    pub fn insert(self, coin: Coin) -> Machine<Collecting> {
+        let credit = self.state.credit + coin.cents();
+        self.into_state(Collecting { credit })
+    }
+
+    /// Dispenses `product`, or gives machine back unchanged when credit is too low.
+    pub fn select(self, product: Product) -> Result<Machine<Dispensing>, Machine<Collecting>> {
+        match self.state.credit.checked_sub(product.price()) {
+            Some(change) => Ok(self.into_state(Dispensing { product, change })),
+            None => Err(self),
+        }
+    }
+
+    pub fn cancel(self) -> Machine<Refunding> {
+        let amount = self.state.credit;
+        self.into_state(Refunding { amount })
+    }
+}
+
+impl Machine<Dispensing> {
+    /// Returns product and change.
+    pub fn take(self) -> (Machine<Idle>, Product, u32) {
+        let (product, change) = (self.state.product, self.state.change);
+        (self.into_state(Idle), product, change)
+    }
+}
+
+impl Machine<Refunding> {
+    /// Returns refunded amount.
+    pub fn take(self) -> (Machine<Idle>, u32) {
+        let amount = self.state.amount;
+        (self.into_state(Idle), amount)
+    }
+}
+
+impl<S: Operational> Machine<S> {
+    pub fn fault(self) -> Machine<OutOfOrder> {
+        self.into_state(OutOfOrder)
+    }
+}
+
+impl Machine<OutOfOrder> {
+    pub fn repair(self) -> Machine<Idle> {
+        self.into_state(Idle)
+    }
+}
@@ -0,0 +1,25 @@
+//! Solution of `20-testing/tests/public_api.rs`: only public API of crate is available.
+
+extern crate solutions;
+
+use solutions::_20_testing::is_leap_year;
+
+#[test]
+fn year_2024_should_be_leap() {
+    assert!(is_leap_year(2024));
+}
+
+#[test]
+fn year_2023_should_not_be_leap() {
+    assert!(!is_leap_year(2023));
+}
+
+#[test]
+fn year_1900_should_not_be_leap() {
+    assert!(!is_leap_year(1900));
+}
+
+#[test]
+fn year_2000_should_be_leap() {
+    assert!(is_leap_year(2000));
+}
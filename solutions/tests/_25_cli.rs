@@ -0,0 +1,81 @@
+//! Solution of `25-cli/tests/cli.rs`: these tests run compiled `count_solution` binary.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+struct Output {
+    code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+/// Runs `count_solution` binary with `args`, writing `stdin` into its standard input.
+fn count_command(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_count_solution"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("count_solution binary must be built");
+    // Binary may exit without reading its input (e.g. when counting a file)
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    let output = child.wait_with_output().unwrap();
+    Output {
+        code: output.status.code().expect("count must not be killed by a signal"),
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: String::from_utf8(output.stderr).unwrap(),
+    }
+}
+
+#[test]
+fn print_help() {
+    let output = count_command(&["--help"], "");
+    assert_eq!(0, output.code);
+    assert!(output.stdout.starts_with("Usage: count [OPTIONS] [FILE]\n"), "stdout: {}", output.stdout);
+    assert_eq!("", output.stderr);
+}
+
+#[test]
+fn count_standard_input() {
+    let output = count_command(&[], "Hello world !\nBye\n");
+    assert_eq!(0, output.code);
+    assert_eq!("2 4 18\n", output.stdout);
+    assert_eq!("", output.stderr);
+}
+
+#[test]
+fn count_selected_units() {
+    let output = count_command(&["-c", "--lines"], "Hello world !\nBye\n");
+    assert_eq!(0, output.code);
+    assert_eq!("2 18\n", output.stdout);
+}
+
+#[test]
+fn count_file() {
+    let path = env::temp_dir().join(format!("workshop_25_cli_{}.txt", std::process::id()));
+    fs::write(&path, "one two\nthree\n").unwrap();
+    let output = count_command(&["-w", path.to_str().unwrap()], "ignored stdin");
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(0, output.code);
+    assert_eq!(format!("3 {}\n", path.display()), output.stdout);
+}
+
+#[test]
+fn fail_when_missing_file() {
+    let output = count_command(&["/this/file/does/not/exist"], "");
+    assert_eq!(1, output.code);
+    assert_eq!("", output.stdout);
+    assert!(output.stderr.starts_with("count: cannot read '/this/file/does/not/exist': "), "stderr: {}", output.stderr);
+}
+
+#[test]
+fn fail_when_unknown_option() {
+    let output = count_command(&["--unknown"], "");
+    assert_eq!(2, output.code);
+    assert_eq!("", output.stdout);
+    assert_eq!("count: unknown option '--unknown'\nTry 'count --help' for more information.\n", output.stderr);
+}